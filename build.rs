@@ -0,0 +1,119 @@
+/// Generates the registry of solutions that the main binary links in-process.
+/// Every `src/bin/YYYY_DD.rs` is included as a module and listed in a `SOLUTIONS` table.
+/// Also generates the example tests of every solution from `data/YYYY/expectations/DD.txt`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut puzzles: Vec<(u16, u8, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    let (year, day) = path
+                        .file_name()?
+                        .to_str()?
+                        .strip_suffix(".rs")
+                        .filter(|s| s.len() == 7)?
+                        .split_once('_')?;
+                    let year: u16 = year.parse().ok()?;
+                    let day: u8 = day.parse().ok()?;
+                    (year >= 2015 && (1..=25).contains(&day))
+                        .then(|| (year, day, path.display().to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    puzzles.sort();
+
+    let mut out = String::new();
+
+    for (year, day, path) in &puzzles {
+        out.push_str(&format!(
+            "#[cfg(not(test))]\n#[path = {path:?}]\nmod day_{year}_{day:02};\n"
+        ));
+    }
+
+    out.push_str("\n/// Every scaffolded solution, in year and day order.\n#[cfg(not(test))]\n");
+    out.push_str("pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[\n");
+    for (year, day, _) in &puzzles {
+        out.push_str(&format!(
+            "    advent_of_code::template::runner::Solution {{ puzzle: advent_of_code::Puzzle::new(advent_of_code::year!({year}), advent_of_code::day!({day})), solve: day_{year}_{day:02}::run_parts }},\n"
+        ));
+    }
+    out.push_str("];\n\n#[cfg(test)]\npub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];\n");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("solutions.rs"), out).unwrap();
+
+    let examples_dir = out_dir.join("examples");
+    fs::create_dir_all(&examples_dir).unwrap();
+    for (year, day, _) in &puzzles {
+        let data_dir = Path::new(&manifest_dir).join("data").join(year.to_string());
+        let tests = generate_example_tests(&data_dir, *day);
+        fs::write(examples_dir.join(format!("{year}_{day:02}.rs")), tests).unwrap();
+    }
+}
+
+/// One test per example and part of a day, included by the `solution!` macro of its binary.
+/// The tests read the example and its expected answer when they run, only the list of tests is generated.
+fn generate_example_tests(data_dir: &Path, day: u8) -> String {
+    let expectations_dir = data_dir.join("expectations");
+    // a missing directory would rerun the build script every time, watch its parent instead.
+    if expectations_dir.exists() {
+        println!("cargo:rerun-if-changed={}", expectations_dir.display());
+    } else {
+        println!("cargo:rerun-if-changed={}", data_dir.display());
+    }
+
+    let Ok(expectations) = fs::read_to_string(expectations_dir.join(format!("{day:02}.txt")))
+    else {
+        return String::new();
+    };
+
+    let mut out = String::new();
+    let mut generated = vec![];
+    for line in expectations.lines() {
+        // malformed lines are reported by the tests, which parse the whole file.
+        let Some((example, part)) = line
+            .split_once(':')
+            .and_then(|(key, _)| key.trim().split_once(' '))
+        else {
+            continue;
+        };
+        let (part, func) = match part.trim() {
+            "part_one" => (1, "part_one"),
+            "part_two" => (2, "part_two"),
+            _ => continue,
+        };
+        // a later line replaces the expectation of an earlier one.
+        if generated.contains(&(example, part)) {
+            continue;
+        }
+        generated.push((example, part));
+
+        let name: String = example
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        out.push_str(&format!(
+            "#[test]\nfn example_{name}_{func}() {{\n    advent_of_code::template::examples::check(super::PUZZLE, {example:?}, {part}, super::{func});\n}}\n\n"
+        ));
+    }
+    out
+}
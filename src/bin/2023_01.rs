@@ -1,4 +1,4 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(2023, 1);
 
 use regex::Regex;
 
@@ -73,25 +73,3 @@ fn find_last_digit(s: &str, look_at_strings: bool) -> Option<char> {
         None => None,
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(
-            "1abc2
-        pqr3stu8vwx
-        a1b2c3d4e5f
-        treb7uchet",
-        );
-        assert_eq!(result, Some(142));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(281));
-    }
-}
@@ -1,11 +1,12 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
+use advent_of_code::parse::{parse_lines, unsigned, ParseError};
 use nom::{
     bytes::complete::tag,
-    character::complete::{alpha1, digit1, multispace1},
+    character::complete::{alpha1, space1},
     combinator::map_res,
     multi::separated_list1,
-    sequence::separated_pair,
+    sequence::{delimited, separated_pair},
     IResult,
 };
 use std::collections::HashMap;
@@ -41,12 +42,8 @@ fn parse_color(input: &str) -> IResult<&str, Color> {
     map_res(alpha1, FromStr::from_str)(input)
 }
 
-fn parse_count(input: &str) -> IResult<&str, u32> {
-    map_res(digit1, FromStr::from_str)(input)
-}
-
 fn parse_color_count(input: &str) -> IResult<&str, (Color, u32)> {
-    let (input, (count, color)) = separated_pair(parse_count, multispace1, parse_color)(input)?;
+    let (input, (count, color)) = separated_pair(unsigned, space1, parse_color)(input)?;
     Ok((input, (color, count)))
 }
 
@@ -55,18 +52,13 @@ fn parse_round(input: &str) -> IResult<&str, Vec<(Color, u32)>> {
 }
 
 fn parse_game(input: &str) -> IResult<&str, Game> {
-    let (input, _) = tag("Game ")(input)?;
-    let (input, id) = parse_count(input)?;
-    let (input, _) = tag(": ")(input)?;
+    let (input, id) = delimited(tag("Game "), unsigned, tag(": "))(input)?;
     let (input, rounds) = separated_list1(tag("; "), parse_round)(input)?;
     Ok((input, Game { id, rounds }))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let games = input
-        .trim()
-        .split('\n')
-        .map(|line| parse_game(line).unwrap().1);
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let games = parse_lines(input, parse_game)?;
 
     let bag_contents: HashMap<_, _> = vec![(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)]
         .into_iter()
@@ -77,39 +69,34 @@ pub fn part_one(input: &str) -> Option<u32> {
             round.iter().all(|(color, count)| {
                 bag_contents
                     .get(color)
-                    .map_or(false, |&bag_count| bag_count >= *count)
+                    .is_some_and(|&bag_count| bag_count >= *count)
             })
         })
     });
 
-    Some(result.map(|game| game.id).sum())
+    Ok(result.map(|game| game.id).sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let games = input
-        .trim()
-        .split('\n')
-        .map(|line| parse_game(line).unwrap().1);
-
-    Some(
-        games
-            .into_iter()
-            .map(|game| {
-                let mut bag_contents: HashMap<_, _> =
-                    vec![(Color::Red, 0), (Color::Green, 0), (Color::Blue, 0)]
-                        .into_iter()
-                        .collect();
-
-                for round in &game.rounds {
-                    for (color, count) in round {
-                        let bag_count = bag_contents.get_mut(color).unwrap();
-                        *bag_count = (*bag_count).max(*count);
-                    }
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let games = parse_lines(input, parse_game)?;
+
+    Ok(games
+        .into_iter()
+        .map(|game| {
+            let mut bag_contents: HashMap<_, _> =
+                vec![(Color::Red, 0), (Color::Green, 0), (Color::Blue, 0)]
+                    .into_iter()
+                    .collect();
+
+            for round in &game.rounds {
+                for (color, count) in round {
+                    let bag_count = bag_contents.get_mut(color).unwrap();
+                    *bag_count = (*bag_count).max(*count);
                 }
-                bag_contents.values().product::<u32>()
-            })
-            .sum(),
-    )
+            }
+            bag_contents.values().product::<u32>()
+        })
+        .sum())
 }
 
 #[cfg(test)]
@@ -118,13 +105,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(2286));
     }
 }
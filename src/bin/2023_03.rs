@@ -0,0 +1,79 @@
+advent_of_code::solution!(2023, 3);
+
+use advent_of_code::grid::{Dir, Grid, Pos};
+use advent_of_code::parse::ParseError;
+
+/// Collects the numbers that are adjacent to each symbol that matches `condition`.
+pub fn process_map<F>(input: &str, condition: F) -> Result<Vec<Vec<u32>>, ParseError>
+where
+    F: Fn(char) -> bool,
+{
+    let map: Grid<char> = input.parse()?;
+    let mut visited = Grid::filled(map.width(), map.height(), false);
+    let is_digit = |pos: Pos| map.get(pos).is_some_and(char::is_ascii_digit);
+
+    let mut results = Vec::new();
+
+    for (pos, &symbol) in map.iter() {
+        if !condition(symbol) {
+            continue;
+        }
+
+        let mut numbers = Vec::new();
+        for neighbor in map.neighbors8(pos) {
+            if !is_digit(neighbor) || visited[neighbor] {
+                continue;
+            }
+
+            // walk back to the first digit of the number, then read it left to right.
+            let mut start = neighbor;
+            while is_digit(start.step(Dir::Left)) {
+                start = start.step(Dir::Left);
+            }
+
+            let mut number = 0;
+            let mut curr = start;
+            while is_digit(curr) {
+                number = number * 10 + map[curr].to_digit(10).unwrap();
+                visited[curr] = true;
+                curr = curr.step(Dir::Right);
+            }
+
+            numbers.push(number);
+        }
+        results.push(numbers);
+    }
+
+    Ok(results)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let results = process_map(input, |c| c.is_ascii_punctuation() && c != '.')?;
+    Ok(results.iter().flatten().sum())
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let results = process_map(input, |c| c == '*')?;
+    Ok(results
+        .iter()
+        .filter(|v| v.len() == 2)
+        .map(|v| v[0] * v[1])
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(467835));
+    }
+}
@@ -0,0 +1,90 @@
+advent_of_code::solution!(2023, 4);
+
+use advent_of_code::parse::{numbers, parse_lines, unsigned, ParseError};
+use nom::{
+    bytes::complete::tag,
+    character::complete::space1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+struct Card {
+    #[allow(dead_code)]
+    id: i32,
+    winning_numbers: HashSet<i32>,
+    given_numbers: HashSet<i32>,
+}
+
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    let (input, (id, winning_numbers, given_numbers)) = tuple((
+        preceded(tag("Card"), preceded(space1, unsigned)),
+        preceded(tag(":"), preceded(space1, numbers)),
+        preceded(tag(" |"), preceded(space1, numbers)),
+    ))(input)?;
+
+    Ok((
+        input,
+        Card {
+            id,
+            winning_numbers: HashSet::from_iter(winning_numbers),
+            given_numbers: HashSet::from_iter(given_numbers),
+        },
+    ))
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    Ok(parse_lines(input, parse_card)?
+        .into_iter()
+        .map(|card| {
+            let mut result = 0;
+            for given_number in card.given_numbers {
+                if card.winning_numbers.contains(&given_number) {
+                    if result == 0 {
+                        result = 1;
+                    } else {
+                        result *= 2;
+                    }
+                }
+            }
+            result
+        })
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let cards = parse_lines(input, parse_card)?;
+
+    let mut counts: Vec<u32> = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let mut won_this_round = card
+            .winning_numbers
+            .intersection(&card.given_numbers)
+            .count();
+        while won_this_round > 0 {
+            counts[i + won_this_round] += counts[i];
+            won_this_round -= 1;
+        }
+    }
+
+    Ok(counts.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(30));
+    }
+}
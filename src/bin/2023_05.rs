@@ -0,0 +1,81 @@
+advent_of_code::solution!(2023, 5);
+
+use advent_of_code::intervals::{RangeMap, RangeSet};
+use advent_of_code::parse::{key_value, labeled, lines, numbers, parse_all, unsigned, ParseError};
+use nom::{
+    character::complete::{multispace1, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+    IResult,
+};
+
+fn parse_mapping(input: &str) -> IResult<&str, RangeMap> {
+    let row = tuple((
+        unsigned,
+        preceded(space1, unsigned),
+        preceded(space1, unsigned),
+    ));
+    map(key_value(lines(row)), |(_, rows)| {
+        RangeMap::from_table(rows)
+    })(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<i64>, Vec<RangeMap>)> {
+    separated_pair(
+        labeled("seeds", numbers),
+        multispace1,
+        separated_list1(multispace1, parse_mapping),
+    )(input)
+}
+
+fn no_seeds(input: &str) -> ParseError {
+    ParseError::at(input, input, "expected at least one seed")
+}
+
+pub fn part_one(input: &str) -> Result<i64, ParseError> {
+    let (seeds, mappings) = parse_all(input, parse_input)?;
+
+    seeds
+        .iter()
+        .map(|seed| mappings.iter().fold(*seed, |curr, map| map.get(curr)))
+        .min()
+        .ok_or_else(|| no_seeds(input))
+}
+
+pub fn part_two(input: &str) -> Result<i64, ParseError> {
+    let (seed_ranges, mappings) = parse_all(input, parse_input)?;
+
+    if seed_ranges.len() % 2 != 0 {
+        return Err(ParseError::at(
+            input,
+            input,
+            "expected seeds in pairs of start and length",
+        ));
+    }
+
+    let seeds: RangeSet = seed_ranges
+        .chunks_exact(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect();
+
+    let locations = mappings.iter().fold(seeds, |curr, map| map.map(&curr));
+    locations.min().ok_or_else(|| no_seeds(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(46));
+    }
+}
@@ -0,0 +1,70 @@
+advent_of_code::solution!(2023, 6);
+
+use advent_of_code::math::{quadratic_below_zero, range_len};
+use advent_of_code::parse::{labeled, numbers, parse_all, ParseError};
+use nom::{character::complete::multispace1, sequence::separated_pair, IResult};
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<u64>, Vec<u64>)> {
+    separated_pair(
+        labeled("Time", numbers),
+        multispace1,
+        labeled("Distance", numbers),
+    )(input)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let (times, distances) = parse_all(input, parse_input)?;
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| find_winning_positions(&Race { time, distance }))
+        .product())
+}
+
+/// Reads the numbers of a line as a single number, ignoring the spaces between them.
+fn join_digits(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, n| {
+        acc * 10_u64.pow(n.checked_ilog10().unwrap_or(0) + 1) + n
+    })
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let (times, distances) = parse_all(input, parse_input)?;
+
+    let race = Race {
+        time: join_digits(&times),
+        distance: join_digits(&distances),
+    };
+
+    Ok(find_winning_positions(&race))
+}
+
+fn find_winning_positions(race: &Race) -> u32 {
+    // holding the button for x ms wins if x * (time - x) > distance, i.e. x² - time * x + distance < 0.
+    let holds = quadratic_below_zero(1, -(race.time as i64), race.distance as i64);
+    range_len(&holds) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(288));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(71503));
+    }
+}
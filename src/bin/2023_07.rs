@@ -1,7 +1,8 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
+use advent_of_code::parse::{parse_lines, unsigned, ParseError};
 use nom::{
-    character::complete::{digit1, multispace0, one_of},
+    character::complete::{one_of, space1},
     combinator::map_res,
     multi::count,
     sequence::separated_pair,
@@ -65,9 +66,9 @@ struct Bet {
 }
 
 fn parse_card(input: &str) -> IResult<&str, Card> {
-    let (input, card) = one_of("AKQJT98765432")(input)?;
-    let card = Card::from_str(&card.to_string()).unwrap();
-    Ok((input, card))
+    map_res(one_of("AKQJT98765432"), |card| {
+        Card::from_str(&card.to_string())
+    })(input)
 }
 
 fn parse_hand(input: &str) -> IResult<&str, Hand> {
@@ -165,23 +166,14 @@ fn parse_bet(with_jokers: bool, input: &str) -> IResult<&str, Bet> {
         } else {
             parse_hand
         },
-        multispace0,
-        map_res(digit1, |s: &str| s.parse()),
+        space1,
+        unsigned,
     )(input)?;
     Ok((input, Bet { hand, amount }))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut bets: Vec<Bet> = input
-        .trim()
-        .lines()
-        .map(|line| match parse_bet(false, line) {
-            Ok((_, bet)) => bet,
-            Err(err) => {
-                panic!("Could not parse input: {}", err);
-            }
-        })
-        .collect();
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let mut bets = parse_lines(input, |line| parse_bet(false, line))?;
 
     bets.sort_by(|fst, snd| {
         let compare_hands = fst.hand.hand_type.cmp(&snd.hand.hand_type);
@@ -202,25 +194,15 @@ pub fn part_one(input: &str) -> Option<u32> {
         }
     });
 
-    Some(
-        bets.iter()
-            .enumerate()
-            .map(|(i, bet)| bet.amount * (i as u32 + 1))
-            .sum::<u32>(),
-    )
+    Ok(bets
+        .iter()
+        .enumerate()
+        .map(|(i, bet)| bet.amount * (i as u32 + 1))
+        .sum::<u32>())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut bets: Vec<Bet> = input
-        .trim()
-        .lines()
-        .map(|line| match parse_bet(true, line) {
-            Ok((_, bet)) => bet,
-            Err(err) => {
-                panic!("Could not parse input: {}", err);
-            }
-        })
-        .collect();
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let mut bets = parse_lines(input, |line| parse_bet(true, line))?;
 
     bets.sort_by(|fst, snd| {
         let compare_hands = fst.hand.hand_type.cmp(&snd.hand.hand_type);
@@ -246,12 +228,11 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     });
 
-    Some(
-        bets.iter()
-            .enumerate()
-            .map(|(i, bet)| bet.amount * (i as u32 + 1))
-            .sum::<u32>(),
-    )
+    Ok(bets
+        .iter()
+        .enumerate()
+        .map(|(i, bet)| bet.amount * (i as u32 + 1))
+        .sum::<u32>())
 }
 
 #[cfg(test)]
@@ -260,14 +241,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6440));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(6440));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         // let result = part_two("JJ23J 10");
-        assert_eq!(result, Some(5905));
+        assert_eq!(result, Ok(5905));
     }
 }
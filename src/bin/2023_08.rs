@@ -0,0 +1,216 @@
+advent_of_code::solution!(2023, 8);
+
+use advent_of_code::cycles::History;
+use advent_of_code::math::crt;
+use advent_of_code::parse::{lines, parse_all, ParseError};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::multispace1,
+    combinator::map,
+    multi::many1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+#[derive(Debug, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Rule {
+    name: String,
+    values: (String, String),
+}
+
+fn is_alphanumeric(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    alt((
+        map(tag("L"), |_| Direction::Left),
+        map(tag("R"), |_| Direction::Right),
+    ))(input)
+}
+
+fn parse_rule(input: &str) -> IResult<&str, Rule> {
+    let (input, (name, _, _, (value1, _, value2), _)) = tuple((
+        take_while_m_n(3, 3, is_alphanumeric),
+        tag(" = "),
+        tag("("),
+        tuple((
+            take_while_m_n(3, 3, is_alphanumeric),
+            tag(", "),
+            take_while_m_n(3, 3, is_alphanumeric),
+        )),
+        tag(")"),
+    ))(input)?;
+
+    Ok((
+        input,
+        Rule {
+            name: name.to_string(),
+            values: (value1.to_string(), value2.to_string()),
+        },
+    ))
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<Direction>, Vec<Rule>)> {
+    separated_pair(many1(parse_direction), multispace1, lines(parse_rule))(input)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let (directions, rules) = parse_all(input, parse_input)?;
+    let rules_map: HashMap<String, (String, String)> = rules
+        .into_iter()
+        .map(|rule| (rule.name, rule.values))
+        .collect();
+
+    let directions = directions.into_iter().cycle();
+
+    let mut curr = "AAA";
+    let mut steps = 0;
+
+    for dir in directions {
+        match dir {
+            Direction::Left => {
+                curr = &rules_map.get(curr).unwrap().0;
+            }
+            Direction::Right => {
+                curr = &rules_map.get(curr).unwrap().1;
+            }
+        }
+        steps += 1;
+        if curr == "ZZZ" {
+            break;
+        }
+    }
+
+    Ok(steps)
+}
+
+pub fn part_two(input: &str) -> Result<usize, String> {
+    let (directions, rules) = parse_all(input, parse_input)?;
+    let rules_map: HashMap<String, (String, String)> = rules
+        .into_iter()
+        .map(|rule| (rule.name, rule.values))
+        .collect();
+
+    let starts: Vec<&str> = rules_map
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(String::as_str)
+        .collect();
+
+    if starts.is_empty() {
+        return Err("no nodes ending with A".to_string());
+    }
+
+    // a ghost repeats itself once it's on the same node at the same direction again,
+    // its loop may have any number of nodes ending with Z at any point.
+    let histories: Vec<History<(&str, usize)>> = starts
+        .par_iter()
+        .map(|start| {
+            History::new((*start, 0), |&(node, i)| {
+                let (left, right) = &rules_map[node];
+                let next = match directions[i] {
+                    Direction::Left => left,
+                    Direction::Right => right,
+                };
+                (next.as_str(), (i + 1) % directions.len())
+            })
+        })
+        .collect();
+
+    let on_z = |history: &History<(&str, usize)>, steps: usize| history.nth(steps).0.ends_with('Z');
+
+    // before every ghost is in its loop, check every step.
+    let all_looping = histories
+        .iter()
+        .map(|h| h.cycle().offset)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    if let Some(steps) = (1..all_looping).find(|steps| histories.iter().all(|h| on_z(h, *steps))) {
+        return Ok(steps);
+    }
+
+    // afterwards, a ghost is on a Z node after `steps` if it's congruent to one of the Z nodes of its loop.
+    let loop_congruences = histories.iter().map(|history| {
+        let cycle = history.cycle();
+        (cycle.offset..cycle.offset + cycle.period)
+            .filter(|steps| on_z(history, *steps))
+            .map(move |steps| (steps as i64, cycle.period as i64))
+            .collect::<Vec<_>>()
+    });
+
+    loop_congruences
+        .multi_cartesian_product()
+        .filter_map(crt)
+        .map(|(steps, period)| {
+            // the first solution once every ghost is in its loop.
+            let (steps, period) = (steps as usize, period as usize);
+            steps + all_looping.saturating_sub(steps).div_ceil(period) * period
+        })
+        .min()
+        .ok_or_else(|| "the ghosts are never on nodes ending with Z at the same time".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+        );
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)",
+        );
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn test_uneven_loops() {
+        let result = part_two(
+            "L
+
+11A = (11Z, XXX)
+11Z = (11B, XXX)
+11B = (11Z, XXX)
+22A = (22B, XXX)
+22B = (22Z, XXX)
+22Z = (22C, XXX)
+22C = (22D, XXX)
+22D = (22Z, XXX)
+XXX = (XXX, XXX)",
+        );
+        assert_eq!(result, Ok(5));
+    }
+}
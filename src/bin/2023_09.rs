@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 pub fn part_one(input: &str) -> Option<i64> {
     Some(
@@ -57,13 +57,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 }
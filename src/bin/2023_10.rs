@@ -0,0 +1,228 @@
+advent_of_code::solution!(2023, 10);
+
+use advent_of_code::geometry::Polygon;
+use advent_of_code::grid::{Dir, Grid, Pos};
+use advent_of_code::parse::ParseError;
+use advent_of_code::search::reachable;
+use advent_of_code::{trace, trace_grid};
+use parse_display::{Display, FromStr};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Display, FromStr, PartialEq, Clone, Copy)]
+enum Tile {
+    #[display("|")]
+    Vertical,
+    #[display("-")]
+    Horizontal,
+    #[display("L")]
+    BendNE,
+    #[display("J")]
+    BendNW,
+    #[display("7")]
+    BendSW,
+    #[display("F")]
+    BendSE,
+    #[display(".")]
+    Ground,
+    #[display("S")]
+    Start,
+}
+
+impl Tile {
+    const PIPES: [Tile; 6] = [
+        Tile::Vertical,
+        Tile::Horizontal,
+        Tile::BendNE,
+        Tile::BendNW,
+        Tile::BendSW,
+        Tile::BendSE,
+    ];
+
+    /// The directions the tile connects to, in the order of `Dir::ALL`. The start may connect anywhere.
+    fn connections(&self) -> &'static [Dir] {
+        match self {
+            Tile::Vertical => &[Dir::Up, Dir::Down],
+            Tile::Horizontal => &[Dir::Right, Dir::Left],
+            Tile::BendNE => &[Dir::Up, Dir::Right],
+            Tile::BendNW => &[Dir::Up, Dir::Left],
+            Tile::BendSW => &[Dir::Down, Dir::Left],
+            Tile::BendSE => &[Dir::Right, Dir::Down],
+            Tile::Ground => &[],
+            Tile::Start => &Dir::ALL,
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let mut map: Grid<Tile> = input.parse()?;
+    let visited = explore_and_mark(&mut map);
+    Ok(*visited.values().max().unwrap() as u32)
+}
+
+/// The directions in which the pipe at `pos` connects to a neighboring pipe that connects back.
+fn connected(map: &Grid<Tile>, pos: Pos) -> Vec<Dir> {
+    map[pos]
+        .connections()
+        .iter()
+        .copied()
+        .filter(|dir| {
+            map.step(pos, *dir)
+                .is_some_and(|next| map[next].connections().contains(&dir.reverse()))
+        })
+        .collect()
+}
+
+/// Replaces the start tile by the pipe it stands for, returns its position.
+fn mark_start(map: &mut Grid<Tile>) -> Pos {
+    let start_pos = map.find(|tile| *tile == Tile::Start).unwrap();
+    let start_connections = connected(map, start_pos);
+    map[start_pos] = Tile::PIPES
+        .into_iter()
+        .find(|pipe| pipe.connections() == start_connections)
+        .unwrap_or_else(|| panic!("Should not happen: {:?}", start_connections));
+    start_pos
+}
+
+/// Walks the loop from the start in both directions, returns the distance of every pipe on the loop.
+/// The start tile is replaced by the pipe it stands for.
+fn explore_and_mark(map: &mut Grid<Tile>) -> HashMap<Pos, usize> {
+    let start_pos = mark_start(map);
+    let visited = reachable([start_pos], |&pos| {
+        connected(map, pos)
+            .into_iter()
+            .map(move |dir| pos.step(dir))
+    });
+
+    if trace::is_enabled() {
+        let steps = Grid::from_fn(map.width(), map.height(), |pos| match visited.get(&pos) {
+            Some(steps) => steps.to_string(),
+            None => ".".to_string(),
+        });
+        trace_grid!(steps, "steps from the start");
+    }
+
+    visited
+}
+
+/// The pipes of the loop in order, from the start.
+fn trace_loop(map: &Grid<Tile>, start_pos: Pos) -> Vec<Pos> {
+    let mut path = vec![start_pos];
+    let mut dir = map[start_pos].connections()[0];
+    let mut pos = start_pos.step(dir);
+
+    while pos != start_pos {
+        path.push(pos);
+        // every pipe has two connections, leave through the one we didn't come from.
+        dir = *map[pos]
+            .connections()
+            .iter()
+            .find(|next| **next != dir.reverse())
+            .unwrap();
+        pos = pos.step(dir);
+    }
+
+    path
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let mut map: Grid<Tile> = input.parse()?;
+    let start_pos = mark_start(&mut map);
+    let path = trace_loop(&map, start_pos);
+
+    // the centers of the pipes form a polygon, the enclosed tiles are its interior lattice points.
+    let polygon = Polygon::new(path);
+
+    if trace::is_enabled() {
+        let on_loop: HashSet<Pos> = polygon.vertices().iter().copied().collect();
+        let sections = Grid::from_fn(map.width(), map.height(), |pos| {
+            if on_loop.contains(&pos) {
+                map[pos].to_string()
+            } else if polygon.contains(pos) {
+                "I".to_string()
+            } else {
+                "O".to_string()
+            }
+        });
+        trace_grid!(sections, "tiles inside (I) and outside (O) of the loop");
+    }
+
+    Ok(polygon.interior_points())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L",
+        );
+        assert_eq!(result, Ok(10));
+    }
+
+    #[test]
+    fn test_inner_loop() {
+        let result = part_two(
+            "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........",
+        );
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn test_unused_loop() {
+        let result = part_two(
+            "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........",
+        );
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn test_large() {
+        let result = part_two(
+            ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...",
+        );
+
+        assert_eq!(result, Ok(8));
+    }
+}
@@ -0,0 +1,124 @@
+advent_of_code::solution!(2023, 11);
+
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::parse::ParseError;
+use advent_of_code::trace_grid;
+use parse_display::{Display, FromStr};
+use std::collections::HashSet;
+
+#[derive(Display, FromStr, PartialEq, Debug, Clone)]
+enum Tile {
+    #[display(".")]
+    Empty,
+    #[display("#")]
+    Galaxy,
+}
+
+struct Universe {
+    galaxies: HashSet<Pos>,
+    rows: usize,
+    cols: usize,
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let original = parse_input(input)?;
+    trace_grid!(render_universe(&original), "original universe");
+    let expanded = expand_universe(&original, 2);
+    trace_grid!(render_universe(&expanded), "expanded universe");
+
+    Ok(find_distances_sum(&expanded))
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let original = parse_input(input)?;
+    let expanded = expand_universe(&original, 1000000);
+    Ok(find_distances_sum(&expanded))
+}
+
+fn parse_input(input: &str) -> Result<Universe, ParseError> {
+    let map: Grid<Tile> = input.parse()?;
+    let galaxies = map
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::Galaxy)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    Ok(Universe {
+        galaxies,
+        rows: map.height(),
+        cols: map.width(),
+    })
+}
+
+fn expand_universe(uni: &Universe, factor: usize) -> Universe {
+    let to_add: usize = factor - 1;
+    let all_xs: HashSet<usize> = uni.galaxies.iter().map(|g| g.x as usize).collect();
+    let all_ys: HashSet<usize> = uni.galaxies.iter().map(|g| g.y as usize).collect();
+
+    let mut empty_row_indexes = vec![];
+    for i in 0..uni.rows {
+        if !all_ys.contains(&i) {
+            empty_row_indexes.push(i);
+        }
+    }
+    let mut empty_col_indexes = vec![];
+    for i in 0..uni.cols {
+        if !all_xs.contains(&i) {
+            empty_col_indexes.push(i);
+        }
+    }
+
+    let mut new_galaxies = HashSet::new();
+    for galaxy in &uni.galaxies {
+        let (x, y) = (galaxy.x as usize, galaxy.y as usize);
+        let empty_cols_before = empty_col_indexes.iter().filter(|i| **i < x).count();
+        let empty_rows_before = empty_row_indexes.iter().filter(|i| **i < y).count();
+        let new_x = x + empty_cols_before * to_add;
+        let new_y = y + empty_rows_before * to_add;
+        new_galaxies.insert(Pos::new(new_x as i64, new_y as i64));
+    }
+
+    Universe {
+        galaxies: new_galaxies,
+        rows: uni.rows + empty_row_indexes.len() * to_add,
+        cols: uni.cols + empty_col_indexes.len() * to_add,
+    }
+}
+
+fn render_universe(uni: &Universe) -> Grid<Tile> {
+    Grid::from_fn(uni.cols, uni.rows, |pos| {
+        if uni.galaxies.contains(&pos) {
+            Tile::Galaxy
+        } else {
+            Tile::Empty
+        }
+    })
+}
+
+fn find_distances_sum(uni: &Universe) -> u64 {
+    let galaxies_coords = uni.galaxies.iter().cloned().collect::<Vec<_>>();
+    let mut total = 0;
+    for i in 0..galaxies_coords.len() {
+        for j in i + 1..galaxies_coords.len() {
+            total += galaxies_coords[i].manhattan(galaxies_coords[j]);
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(374));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(82000210));
+    }
+}
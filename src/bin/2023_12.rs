@@ -1,4 +1,4 @@
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 use cached::proc_macro::cached;
 
@@ -69,14 +69,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         // let result = part_one("?.? 1");
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(525152));
     }
 }
@@ -0,0 +1,140 @@
+advent_of_code::solution!(2023, 13);
+
+use advent_of_code::grid::Grid;
+use advent_of_code::parse::{paragraphs, ParseError};
+use advent_of_code::{trace, trace_grid};
+
+fn parse_input(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    // lines of errors count from the start of their pattern, the context shows the line anyway.
+    paragraphs(input).map(str::parse).collect()
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let grids = parse_input(input)?;
+
+    let mut total = 0;
+    for grid in grids {
+        trace_grid!(grid);
+        if let Some(idx) = find_horizontal_reflection(&grid) {
+            trace!("found horizontal reflection at {idx}");
+            total += idx * 100;
+        } else {
+            let transposed = grid.transpose();
+            if let Some(idx) = find_horizontal_reflection(&transposed) {
+                trace!("found vertical reflection at {idx}");
+                total += idx;
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+fn find_horizontal_reflection(grid: &Grid<char>) -> Option<u32> {
+    for i in 1..grid.height() {
+        let reflection_span = i.min(grid.height() - i);
+        let mut reflection_found = true;
+        for j in 1..=reflection_span {
+            if grid.row(i - j) != grid.row(i + j - 1) {
+                reflection_found = false;
+                break;
+            }
+        }
+        if reflection_found {
+            return Some(i as u32);
+        }
+    }
+    None
+}
+
+fn find_reflection_candidates(grid: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut results = vec![];
+    for i in 1..grid.height() {
+        let reflection_span = i.min(grid.height() - i);
+        let mut diff_total = 0;
+        for j in 1..=reflection_span {
+            diff_total += diff_vecs(grid.row(i - j), grid.row(i + j - 1));
+        }
+        results.push((i, diff_total));
+    }
+    results.sort_by_key(|(_, diff)| *diff);
+    results
+}
+
+fn diff_vecs<T: PartialEq>(vec1: &[T], vec2: &[T]) -> usize {
+    vec1.iter().zip(vec2).filter(|&(a, b)| a != b).count()
+}
+
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let grids = parse_input(input)?;
+
+    let mut total = 0;
+    for grid in grids {
+        trace_grid!(grid);
+        match find_reflection_candidates(&grid)[..] {
+            [(_, 0), (idx, 1), ..] | [(idx, 1), ..] => {
+                trace!("found horizontal reflection at {idx}");
+                total += idx * 100;
+            }
+            _ => {
+                let transposed = grid.transpose();
+                match find_reflection_candidates(&transposed)[..] {
+                    [(_, 0), (idx, 1), ..] | [(idx, 1), ..] => {
+                        trace!("found vertical reflection at {idx}");
+                        total += idx;
+                    }
+                    _ => {
+                        panic!("no reflection found!")
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(405));
+    }
+
+    #[test]
+    fn test_bug1() {
+        let result = part_one(
+            "#.##...
+####...
+.##.#.#
+.###..#
+#..#.##
+.###..#
+.###..#",
+        );
+        assert_eq!(result, Ok(600));
+    }
+
+    #[test]
+    fn test_simple_part_two() {
+        let result = part_one(
+            "#.##...
+####...
+.##.#.#
+.###..#
+#..#.##
+.###..#
+.###..#",
+        );
+        assert_eq!(result, Ok(600));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(400));
+    }
+}
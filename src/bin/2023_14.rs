@@ -0,0 +1,148 @@
+advent_of_code::solution!(2023, 14);
+
+use advent_of_code::cycles::History;
+use advent_of_code::grid::Grid;
+use advent_of_code::parse::ParseError;
+use advent_of_code::trace;
+use advent_of_code::viz::{self, Frame};
+use itertools::Itertools;
+use parse_display::{Display, FromStr};
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq, Hash)]
+enum Tile {
+    #[display(".")]
+    Empty,
+    #[display("#")]
+    Cube,
+    #[display("O")]
+    Round,
+}
+
+/// The load of the round rocks on the north support beams.
+fn north_load(grid: &Grid<Tile>) -> usize {
+    grid.iter()
+        .filter(|(_, tile)| **tile == Tile::Round)
+        .map(|(pos, _)| grid.height() - pos.y as usize)
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let grid: Grid<Tile> = input.parse()?;
+    let tilted = shift_north(&grid);
+    record_tilt(&grid, &tilted, "north");
+    Ok(north_load(&tilted))
+}
+
+fn rounds_first(a: &Tile, b: &Tile) -> std::cmp::Ordering {
+    match (a, b) {
+        (Tile::Round, Tile::Empty) => std::cmp::Ordering::Less,
+        (Tile::Empty, Tile::Round) => std::cmp::Ordering::Greater,
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+fn rounds_last(a: &Tile, b: &Tile) -> std::cmp::Ordering {
+    match (a, b) {
+        (Tile::Round, Tile::Empty) => std::cmp::Ordering::Greater,
+        (Tile::Empty, Tile::Round) => std::cmp::Ordering::Less,
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+fn shift_north(grid: &Grid<Tile>) -> Grid<Tile> {
+    shift_with_compare(&grid.transpose(), rounds_first).transpose()
+}
+
+fn shift_south(grid: &Grid<Tile>) -> Grid<Tile> {
+    shift_with_compare(&grid.transpose(), rounds_last).transpose()
+}
+
+fn shift_east(grid: &Grid<Tile>) -> Grid<Tile> {
+    shift_with_compare(grid, rounds_last)
+}
+
+fn shift_west(grid: &Grid<Tile>) -> Grid<Tile> {
+    shift_with_compare(grid, rounds_first)
+}
+
+/// Sorts the tiles between the cubes of every row with `compare`.
+fn shift_with_compare<F>(grid: &Grid<Tile>, compare: F) -> Grid<Tile>
+where
+    F: Fn(&Tile, &Tile) -> std::cmp::Ordering + Copy,
+{
+    let shifted = grid
+        .rows()
+        .map(|row| {
+            Itertools::intersperse(
+                row.split(|tile| *tile == Tile::Cube).map(|group| {
+                    let mut group: Vec<_> = group.to_vec();
+                    group.sort_by(compare);
+                    group
+                }),
+                vec![Tile::Cube],
+            )
+            .flatten()
+            .collect::<Vec<_>>()
+        })
+        .collect();
+    // sorting keeps the length of every row.
+    Grid::from_rows(shifted).unwrap()
+}
+
+/// Records the tilted platform, with the rocks that moved highlighted.
+fn record_tilt(before: &Grid<Tile>, after: &Grid<Tile>, direction: &str) {
+    viz::record(|| {
+        let moved = after
+            .iter()
+            .filter(|(pos, tile)| **tile == Tile::Round && before[*pos] != Tile::Round)
+            .map(|(pos, _)| pos);
+        Frame::from_grid(after)
+            .highlight(moved)
+            .caption(format!("tilted {direction}"))
+    });
+}
+
+/// Tilts the platform north, west, south and east.
+fn spin(grid: &Grid<Tile>) -> Grid<Tile> {
+    let mut grid = grid.clone();
+    for (direction, shift) in [
+        ("north", shift_north as fn(&Grid<Tile>) -> Grid<Tile>),
+        ("west", shift_west),
+        ("south", shift_south),
+        ("east", shift_east),
+    ] {
+        let tilted = shift(&grid);
+        record_tilt(&grid, &tilted, direction);
+        grid = tilted;
+    }
+    grid
+}
+
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let grid: Grid<Tile> = input.parse()?;
+    let history = History::new(grid, spin);
+    let cycle = history.cycle();
+    trace!(
+        "the platform repeats every {} spins after {} spins",
+        cycle.period,
+        cycle.offset
+    );
+    Ok(north_load(history.nth(1_000_000_000)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(136));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(64));
+    }
+}
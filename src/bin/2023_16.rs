@@ -0,0 +1,152 @@
+advent_of_code::solution!(2023, 16);
+
+use advent_of_code::grid::{Dir, Grid, Pos};
+use advent_of_code::parse::ParseError;
+use advent_of_code::search::reachable;
+use advent_of_code::viz::{self, Frame};
+use parse_display::{Display, FromStr};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Display, FromStr, PartialEq, Eq)]
+enum Tile {
+    #[display(".")]
+    Empty,
+    #[display("/")]
+    MirrorSlash,
+    #[display("\\")]
+    MirrorBackslash,
+    #[display("|")]
+    SplitterVertical,
+    #[display("-")]
+    SplitterHorizontal,
+}
+
+struct EnergyMap {
+    /// The step at which every energized tile was first reached by a beam.
+    visited: HashMap<Pos, usize>,
+}
+
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let grid: Grid<Tile> = input.parse()?;
+    let energy_map = energize_grid(&grid, Pos::new(-1, 0), Dir::Right);
+    if viz::is_recording() {
+        record_beams(&energy_map, &grid);
+    }
+    Ok(energy_map.visited.len())
+}
+
+/// The directions in which a beam that enters `tile` going `dir` leaves it.
+fn deflect(tile: &Tile, dir: Dir) -> Vec<Dir> {
+    match tile {
+        Tile::Empty => vec![dir],
+        Tile::MirrorSlash => vec![match dir {
+            Dir::Up => Dir::Right,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Right => Dir::Up,
+        }],
+        Tile::MirrorBackslash => vec![match dir {
+            Dir::Up => Dir::Left,
+            Dir::Down => Dir::Right,
+            Dir::Left => Dir::Up,
+            Dir::Right => Dir::Down,
+        }],
+        Tile::SplitterVertical if !dir.is_vertical() => vec![Dir::Up, Dir::Down],
+        Tile::SplitterHorizontal if dir.is_vertical() => vec![Dir::Left, Dir::Right],
+        Tile::SplitterVertical | Tile::SplitterHorizontal => vec![dir],
+    }
+}
+
+/// Follows the beam that enters the grid from `start_pos`, which lies just outside of it.
+fn energize_grid(grid: &Grid<Tile>, start_pos: Pos, dir: Dir) -> EnergyMap {
+    let beams = reachable([(start_pos, dir)], |&(pos, dir)| {
+        let next = pos.step(dir);
+        grid.get(next)
+            .into_iter()
+            .flat_map(move |tile| deflect(tile, dir).into_iter().map(move |dir| (next, dir)))
+    });
+
+    let mut visited: HashMap<Pos, usize> = HashMap::new();
+    for ((pos, _), step) in beams
+        .into_iter()
+        .filter(|((pos, _), _)| grid.contains(*pos))
+    {
+        visited
+            .entry(pos)
+            .and_modify(|first| *first = step.min(*first))
+            .or_insert(step);
+    }
+
+    EnergyMap { visited }
+}
+
+/// Records one frame per step of the beams, energized empty tiles are shown as `#` and the
+/// tiles reached in that step are highlighted.
+fn record_beams(energy_map: &EnergyMap, grid: &Grid<Tile>) {
+    let last_step = energy_map.visited.values().copied().max().unwrap_or(0);
+
+    for step in 1..=last_step {
+        viz::record(|| {
+            let cells = Grid::from_fn(grid.width(), grid.height(), |pos| {
+                match (&grid[pos], energy_map.visited.get(&pos)) {
+                    (Tile::Empty, Some(first)) if *first <= step => '#',
+                    (tile, _) => tile.to_string().chars().next().unwrap_or('.'),
+                }
+            });
+            let frontier = energy_map
+                .visited
+                .iter()
+                .filter(|(_, first)| **first == step)
+                .map(|(pos, _)| *pos);
+            let energized = energy_map.visited.values().filter(|first| **first <= step);
+
+            Frame::new(cells)
+                .highlight(frontier)
+                .caption(format!("step {step}: {} energized", energized.count()))
+        });
+    }
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let grid: Grid<Tile> = input.parse()?;
+    let rows = grid.height() as i64;
+    let cols = grid.width() as i64;
+
+    let from_left: Vec<(Pos, Dir)> = (0..rows).map(|y| (Pos::new(-1, y), Dir::Right)).collect();
+    let from_right: Vec<(Pos, Dir)> = (0..rows).map(|y| (Pos::new(cols, y), Dir::Left)).collect();
+    let from_above: Vec<(Pos, Dir)> = (0..cols).map(|x| (Pos::new(x, -1), Dir::Down)).collect();
+    let from_below: Vec<(Pos, Dir)> = (0..cols).map(|x| (Pos::new(x, rows), Dir::Up)).collect();
+
+    let all_starts: Vec<(Pos, Dir)> = [from_left, from_right, from_above, from_below]
+        .iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+    Ok(all_starts
+        .par_iter()
+        .map(|(start_pos, dir)| {
+            let energy_map = energize_grid(&grid, *start_pos, *dir);
+            energy_map.visited.len() as u32
+        })
+        .max()
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(46));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(51));
+    }
+}
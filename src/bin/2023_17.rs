@@ -0,0 +1,124 @@
+advent_of_code::solution!(2023, 17);
+
+use advent_of_code::grid::{Dir, Grid, Pos};
+use advent_of_code::search::astar;
+
+type DirWithCount = (Dir, u8);
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+struct State {
+    pos: Pos,
+    dir_with_count: DirWithCount,
+}
+
+fn get_cost(
+    map: &Grid<u8>,
+    source: Pos,
+    destination: Pos,
+    min_length: u8,
+    crucible_logic: fn(DirWithCount) -> Vec<DirWithCount>,
+) -> Option<usize> {
+    // the crucible may leave the source in either direction.
+    let starts = [Dir::Right, Dir::Down].map(|dir| State {
+        pos: source,
+        dir_with_count: (dir, 0),
+    });
+
+    astar(
+        starts,
+        |state| {
+            get_next(*state, map, crucible_logic)
+                .into_iter()
+                .map(|next| (next, map[next.pos] as usize))
+        },
+        // every block costs at least 1, so the distance never overestimates.
+        |state| state.pos.manhattan(destination) as usize,
+        |state| state.pos == destination && state.dir_with_count.1 >= min_length,
+    )
+    .cost()
+}
+
+fn crucible_logic_part1((dir, count): DirWithCount) -> Vec<DirWithCount> {
+    let mut turns = vec![(dir.turn_right(), 1), (dir.turn_left(), 1)];
+
+    if count < 3 {
+        turns.push((dir, count + 1));
+    }
+    turns
+}
+
+fn crucible_logic_part2((dir, count): DirWithCount) -> Vec<DirWithCount> {
+    let mut turns = vec![];
+
+    if count >= 4 {
+        turns.extend([(dir.turn_right(), 1), (dir.turn_left(), 1)]);
+    };
+
+    if count < 10 {
+        turns.push((dir, count + 1));
+    }
+    turns
+}
+
+fn get_next(
+    state: State,
+    map: &Grid<u8>,
+    crucible_logic: fn(DirWithCount) -> Vec<DirWithCount>,
+) -> Vec<State> {
+    crucible_logic(state.dir_with_count)
+        .into_iter()
+        .filter_map(|(dir, count)| {
+            map.step(state.pos, dir).map(|pos| State {
+                pos,
+                dir_with_count: (dir, count),
+            })
+        })
+        .collect()
+}
+
+fn bottom_right(map: &Grid<u8>) -> Pos {
+    Pos::new(map.width() as i64 - 1, map.height() as i64 - 1)
+}
+
+pub fn part_one(input: &str) -> Result<usize, String> {
+    let map: Grid<u8> = input.parse()?;
+    let destination = bottom_right(&map);
+    get_cost(&map, Pos::new(0, 0), destination, 1, crucible_logic_part1)
+        .ok_or_else(|| format!("no path to {destination}"))
+}
+
+pub fn part_two(input: &str) -> Result<usize, String> {
+    let map: Grid<u8> = input.parse()?;
+    let destination = bottom_right(&map);
+    get_cost(&map, Pos::new(0, 0), destination, 4, crucible_logic_part2)
+        .ok_or_else(|| format!("no path to {destination}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(102));
+    }
+
+    #[test]
+    fn test_big_crucible() {
+        let result = part_two(
+            "111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+        );
+        assert_eq!(result, Ok(71));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(94));
+    }
+}
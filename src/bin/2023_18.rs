@@ -1,5 +1,7 @@
-advent_of_code::solution!(18);
+advent_of_code::solution!(2023, 18);
 
+use advent_of_code::geometry::Polygon;
+use advent_of_code::grid::{self, Pos};
 use parse_display::{Display, FromStr};
 
 #[derive(Debug, Display, FromStr, PartialEq, Eq, Clone, Copy)]
@@ -30,48 +32,36 @@ fn parse_input(input: &str) -> Vec<Instruction> {
         .collect()
 }
 
+impl From<Dir> for grid::Dir {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::Left => grid::Dir::Left,
+            Dir::Right => grid::Dir::Right,
+            Dir::Up => grid::Dir::Up,
+            Dir::Down => grid::Dir::Down,
+        }
+    }
+}
+
 pub fn part_one(input: &str) -> Option<u64> {
     let instructions = parse_input(input);
-    Some(shoelace(
-        instructions
-            .iter()
-            .map(|ins| (ins.dir, ins.count))
-            .collect::<Vec<_>>(),
+    Some(lagoon_size(
+        instructions.iter().map(|ins| (ins.dir, ins.count)),
     ))
 }
 
-fn shoelace(instructions: Vec<(Dir, i64)>) -> u64 {
-    let mut pos = (0, 0);
-    let mut points = vec![(0, 0)];
-
-    for (dir, count) in &instructions {
-        let (dx, dy) = match dir {
-            Dir::Right => (*count, 0),
-            Dir::Down => (0, *count),
-            Dir::Left => (-*count, 0),
-            Dir::Up => (0, -*count),
-        };
-        pos = (pos.0 + dx, pos.1 + dy);
-        points.push(pos);
-    }
-
-    let mut area: i64 = instructions.iter().map(|(_, count)| count).sum();
-    for i in 1..points.len() {
-        let (x1, y1) = points[i - 1];
-        let (x2, y2) = points[i];
-        area += (x1 * y2) - (x2 * y1);
-    }
-
-    (area / 2).unsigned_abs() + 1
+/// The number of cubic meters dug out by the trench and its interior.
+fn lagoon_size(instructions: impl IntoIterator<Item = (Dir, i64)>) -> u64 {
+    let moves = instructions
+        .into_iter()
+        .map(|(dir, count)| (dir.into(), count));
+    Polygon::from_moves(Pos::default(), moves).lattice_points()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let instructions = parse_input(input);
-    Some(shoelace(
-        instructions
-            .iter()
-            .map(|ins| decode_hex(&ins.hex_code))
-            .collect::<Vec<_>>(),
+    Some(lagoon_size(
+        instructions.iter().map(|ins| decode_hex(&ins.hex_code)),
     ))
 }
 
@@ -97,13 +87,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(62));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(952408144115));
     }
 }
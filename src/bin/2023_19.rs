@@ -1,14 +1,17 @@
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19);
 
+use advent_of_code::intervals::RangeSet;
+use advent_of_code::parse::{lines, parse_all, unsigned, ParseError};
 use nom::{
-    bytes::complete::{tag, take_while},
-    character::complete::alpha1,
-    combinator::map_res,
-    sequence::{preceded, tuple},
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, multispace1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
     IResult,
 };
 use std::collections::HashMap;
-use std::str::FromStr;
 
 #[derive(Debug)]
 enum Operator {
@@ -52,20 +55,16 @@ struct Point {
     s: u32,
 }
 
-fn parse_number(input: &str) -> IResult<&str, u32> {
-    map_res(take_while(|c: char| c.is_numeric()), FromStr::from_str)(input)
-}
-
 fn parse_point(input: &str) -> IResult<&str, Point> {
     let (input, (_, x, _, m, _, a, _, s, _)) = tuple((
         tag("{"),
-        preceded(tag("x="), parse_number),
+        preceded(tag("x="), unsigned),
         tag(","),
-        preceded(tag("m="), parse_number),
+        preceded(tag("m="), unsigned),
         tag(","),
-        preceded(tag("a="), parse_number),
+        preceded(tag("a="), unsigned),
         tag(","),
-        preceded(tag("s="), parse_number),
+        preceded(tag("s="), unsigned),
         tag("}"),
     ))(input)?;
 
@@ -87,20 +86,19 @@ fn parse_action(input: &str) -> IResult<&str, Action> {
 }
 
 fn parse_rule(input: &str) -> IResult<&str, Rule> {
-    if let Ok((input, cond)) = parse_condition(input) {
-        let (input, _) = tag(":")(input)?;
-        let (input, action) = parse_action(input)?;
-        Ok((input, Rule::If { cond, action }))
-    } else {
-        let (input, action) = parse_action(input)?;
-        Ok((input, Rule::Do { action }))
-    }
+    alt((
+        map(
+            separated_pair(parse_condition, tag(":"), parse_action),
+            |(cond, action)| Rule::If { cond, action },
+        ),
+        map(parse_action, |action| Rule::Do { action }),
+    ))(input)
 }
 
 fn parse_workflow(input: &str) -> IResult<&str, (String, Vec<Rule>)> {
     let (input, name) = alpha1(input)?;
     let (input, _) = tag("{")(input)?;
-    let (input, rules) = nom::multi::separated_list1(tag(","), parse_rule)(input)?;
+    let (input, rules) = separated_list1(tag(","), parse_rule)(input)?;
     let (input, _) = tag("}")(input)?;
 
     Ok((input, (name.to_string(), rules)))
@@ -108,25 +106,19 @@ fn parse_workflow(input: &str) -> IResult<&str, (String, Vec<Rule>)> {
 
 fn parse_condition(input: &str) -> IResult<&str, Condition> {
     let (input, (variable, operator, value)) = tuple((
-        take_while(|c: char| c.is_alphabetic()),
-        take_while(|c: char| c == '<' || c == '>'),
-        parse_number,
+        alt((
+            map(tag("x"), |_| Variable::X),
+            map(tag("m"), |_| Variable::M),
+            map(tag("a"), |_| Variable::A),
+            map(tag("s"), |_| Variable::S),
+        )),
+        alt((
+            map(tag("<"), |_| Operator::LessThan),
+            map(tag(">"), |_| Operator::GreaterThan),
+        )),
+        unsigned,
     ))(input)?;
 
-    let operator = match operator {
-        "<" => Operator::LessThan,
-        ">" => Operator::GreaterThan,
-        _ => panic!("Invalid operator"),
-    };
-
-    let variable = match variable {
-        "x" => Variable::X,
-        "m" => Variable::M,
-        "a" => Variable::A,
-        "s" => Variable::S,
-        _ => panic!("Invalid variable"),
-    };
-
     Ok((
         input,
         Condition {
@@ -137,34 +129,24 @@ fn parse_condition(input: &str) -> IResult<&str, Condition> {
     ))
 }
 
-fn parse_input(input: &str) -> (HashMap<String, Vec<Rule>>, Vec<Point>) {
-    let mut workflows = HashMap::new();
-    let mut points = Vec::new();
-
-    for line in input.lines() {
-        if line.is_empty() {
-            continue;
-        } else if line.contains('=') {
-            let (_, point) = parse_point(line).unwrap();
-            points.push(point);
-        } else {
-            let (_, (name, rules)) = parse_workflow(line).unwrap();
-            workflows.insert(name, rules);
-        }
-    }
+type Input = (HashMap<String, Vec<Rule>>, Vec<Point>);
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let (workflows, points) = parse_all(
+        input,
+        separated_pair(lines(parse_workflow), multispace1, lines(parse_point)),
+    )?;
 
-    (workflows, points)
+    Ok((workflows.into_iter().collect(), points))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (workflows, points) = parse_input(input);
-    Some(
-        points
-            .iter()
-            .filter(|p| is_accepted(&workflows, p))
-            .map(|p| p.x + p.m + p.a + p.s)
-            .sum(),
-    )
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let (workflows, points) = parse_input(input)?;
+    Ok(points
+        .iter()
+        .filter(|p| is_accepted(&workflows, p))
+        .map(|p| p.x + p.m + p.a + p.s)
+        .sum())
 }
 
 fn is_accepted(workflows: &HashMap<String, Vec<Rule>>, point: &Point) -> bool {
@@ -208,55 +190,58 @@ fn is_accepted(workflows: &HashMap<String, Vec<Rule>>, point: &Point) -> bool {
     }
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (workflows, _) = parse_input(input);
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let (workflows, _) = parse_input(input)?;
     let candidates = find_candidates(&workflows);
-    Some(candidates.iter().map(|c| c.find_combinations()).sum())
+    Ok(candidates.iter().map(|c| c.find_combinations()).sum())
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Clone)]
 struct Candidate {
-    x: (u32, u32),
-    m: (u32, u32),
-    a: (u32, u32),
-    s: (u32, u32),
+    x: RangeSet,
+    m: RangeSet,
+    a: RangeSet,
+    s: RangeSet,
 }
 
 impl Candidate {
-    fn intersect_var(&self, var: &Variable, range: (u32, u32)) -> Candidate {
-        let mut res = self.clone();
+    fn var_mut(&mut self, var: &Variable) -> &mut RangeSet {
         match var {
-            Variable::X => {
-                res.x = intersect_range(res.x, range);
-            }
-            Variable::M => {
-                res.m = intersect_range(res.m, range);
-            }
-            Variable::A => {
-                res.a = intersect_range(res.a, range);
-            }
-            Variable::S => {
-                res.s = intersect_range(res.s, range);
-            }
+            Variable::X => &mut self.x,
+            Variable::M => &mut self.m,
+            Variable::A => &mut self.a,
+            Variable::S => &mut self.s,
         }
-        res
+    }
+
+    /// Splits the candidate in the part that satisfies `cond` and the part that doesn't.
+    fn split(&self, cond: &Condition) -> (Candidate, Candidate) {
+        let holds = condition_to_range(cond);
+        let (mut true_branch, mut false_branch) = (self.clone(), self.clone());
+
+        let values = true_branch.var_mut(&cond.variable);
+        *values = values.intersection(&holds);
+        let values = false_branch.var_mut(&cond.variable);
+        *values = values.difference(&holds);
+
+        (true_branch, false_branch)
     }
 
     fn find_combinations(&self) -> usize {
-        let x_range = self.x.1 - self.x.0 + 1;
-        let m_range = self.m.1 - self.m.0 + 1;
-        let a_range = self.a.1 - self.a.0 + 1;
-        let s_range = self.s.1 - self.s.0 + 1;
-        x_range as usize * m_range as usize * a_range as usize * s_range as usize
+        [&self.x, &self.m, &self.a, &self.s]
+            .iter()
+            .map(|values| values.len() as usize)
+            .product()
     }
 }
 
 fn find_candidates(workflows: &HashMap<String, Vec<Rule>>) -> Vec<Candidate> {
+    let ratings = RangeSet::from(1..4001);
     let initial = Candidate {
-        x: (1, 4000),
-        m: (1, 4000),
-        a: (1, 4000),
-        s: (1, 4000),
+        x: ratings.clone(),
+        m: ratings.clone(),
+        a: ratings.clone(),
+        s: ratings,
     };
 
     let mut to_visit = vec![("in", initial)];
@@ -280,9 +265,7 @@ fn find_candidates(workflows: &HashMap<String, Vec<Rule>>) -> Vec<Candidate> {
                 },
 
                 Rule::If { cond, action } => {
-                    let (var, true_range, false_range) = condition_to_ranges(cond);
-                    let true_branch = curr.intersect_var(var, true_range);
-                    let false_branch = curr.intersect_var(var, false_range);
+                    let (true_branch, false_branch) = curr.split(cond);
                     match action {
                         Action::Accept => {
                             results.push(true_branch);
@@ -302,29 +285,12 @@ fn find_candidates(workflows: &HashMap<String, Vec<Rule>>) -> Vec<Candidate> {
     results
 }
 
-fn condition_to_ranges(cond: &Condition) -> (&Variable, (u32, u32), (u32, u32)) {
-    match cond {
-        Condition {
-            variable,
-            operator: Operator::GreaterThan,
-            value,
-        } => (variable, (*value + 1, 4000), (1, *value)),
-        Condition {
-            variable,
-            operator: Operator::LessThan,
-            value,
-        } => (variable, (1, *value - 1), (*value, 4000)),
-    }
-}
-
-fn intersect_range((start1, end1): (u32, u32), (start2, end2): (u32, u32)) -> (u32, u32) {
-    let start = start1.max(start2);
-    let end = end1.min(end2);
-
-    if start < end {
-        (start, end)
-    } else {
-        panic!("Can't create disjoint range: ({},{})", start, end);
+/// The ratings for which `cond` holds.
+fn condition_to_range(cond: &Condition) -> RangeSet {
+    let value = i64::from(cond.value);
+    match cond.operator {
+        Operator::GreaterThan => RangeSet::from(value + 1..4001),
+        Operator::LessThan => RangeSet::from(1..value),
     }
 }
 
@@ -334,13 +300,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(19114));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(167409079868000));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(167409079868000));
     }
 }
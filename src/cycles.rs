@@ -0,0 +1,197 @@
+/// Cycle detection for simulations that repeat themselves, to skip ahead to a far away step.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cycle in the states `x0`, `x1 = step(x0)`, `x2 = step(x1)`, ...
+/// Every state from `offset` on is repeated `period` steps later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub offset: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The index of a state before the end of the first cycle that equals the state after `n` steps.
+    #[must_use]
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.offset {
+            n
+        } else {
+            self.offset + (n - self.offset) % self.period
+        }
+    }
+
+    /// The state after `n` steps from `start`, reached in less than `offset + period` steps.
+    pub fn nth<S>(&self, start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.index(n)).fold(start, |state, _| step(&state))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Floyd's tortoise and hare, finds the cycle with a constant amount of memory.
+/// Never returns if the states don't repeat.
+pub fn floyd<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // the hare is now a multiple of the period ahead, so they meet again at the start of the cycle.
+    let mut offset = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        offset += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { offset, period }
+}
+
+/// Brent's algorithm, finds the cycle with a constant amount of memory and fewer steps than `floyd`.
+/// Never returns if the states don't repeat.
+pub fn brent<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut offset = 0;
+    tortoise = start.clone();
+    hare = (0..period).fold(start, |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        offset += 1;
+    }
+
+    Cycle { offset, period }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Every state up to the end of the first cycle, so states after any number of steps can be
+/// looked up without stepping again. Use it when steps are expensive and states are small enough to keep.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S: Clone + Eq + Hash> History<S> {
+    /// Steps from `start` until a state repeats, every step is taken once.
+    /// Never returns if the states don't repeat.
+    pub fn new(start: S, mut step: impl FnMut(&S) -> S) -> Self {
+        let mut seen = HashMap::new();
+        let mut states = vec![];
+        let mut state = start;
+
+        while !seen.contains_key(&state) {
+            seen.insert(state.clone(), states.len());
+            let next = step(&state);
+            states.push(state);
+            state = next;
+        }
+
+        let offset = seen[&state];
+        Self {
+            cycle: Cycle {
+                offset,
+                period: states.len() - offset,
+            },
+            states,
+        }
+    }
+
+    #[must_use]
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// The states from the start to the end of the first cycle.
+    #[must_use]
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// The state after `n` steps.
+    #[must_use]
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.index(n)]
+    }
+}
+
+/// The state after `n` steps from `start`, skipping the repetitions of its cycle.
+pub fn nth_state<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    History::new(start, step).nth(n).clone()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{brent, floyd, nth_state, Cycle, History};
+
+    fn double(x: &u32) -> u32 {
+        (x * 2) % 10
+    }
+
+    #[test]
+    fn detects_cycles() {
+        // 1, 2, 4, 8, 6, 2, 4, ...
+        let expected = Cycle {
+            offset: 1,
+            period: 4,
+        };
+        assert_eq!(floyd(1, double), expected);
+        assert_eq!(brent(1, double), expected);
+        assert_eq!(History::new(1, double).cycle(), expected);
+
+        // 6, 2, 4, 8, 6, ...
+        assert_eq!(brent(6, double).offset, 0);
+        assert_eq!(floyd(5, double).period, 1);
+    }
+
+    #[test]
+    fn skips_ahead() {
+        let history = History::new(1, double);
+        assert_eq!(*history.nth(0), 1);
+        assert_eq!(*history.nth(3), 8);
+        assert_eq!(*history.nth(1_000_000_000), 6);
+
+        assert_eq!(brent(1, double).nth(1, double, 10), 4);
+        assert_eq!(nth_state(1, double, 10), 4);
+
+        let mut steps = 0;
+        let x = nth_state(
+            1,
+            |x| {
+                steps += 1;
+                double(x)
+            },
+            1_000_000_000,
+        );
+        assert_eq!(x, 6);
+        assert_eq!(steps, 5);
+    }
+}
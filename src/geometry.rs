@@ -0,0 +1,171 @@
+/// Polygons with integer vertices: areas, lattice points and point-in-polygon tests.
+use crate::grid::{Dir, Pos};
+use crate::math::gcd;
+
+/// A closed polygon, every vertex is connected to the next one and the last one to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Pos>,
+}
+
+impl Polygon {
+    #[must_use]
+    pub fn new(vertices: Vec<Pos>) -> Self {
+        Self { vertices }
+    }
+
+    /// Follows the `(direction, length)` moves from `start`, the end of every move is a vertex.
+    /// The moves are expected to lead back to `start`.
+    pub fn from_moves(start: Pos, moves: impl IntoIterator<Item = (Dir, i64)>) -> Self {
+        let mut vertices = vec![start];
+        let mut pos = start;
+
+        for (dir, length) in moves {
+            let offset = dir.offset();
+            pos = Pos::new(pos.x + offset.x * length, pos.y + offset.y * length);
+            vertices.push(pos);
+        }
+
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    #[must_use]
+    pub fn vertices(&self) -> &[Pos] {
+        &self.vertices
+    }
+
+    /// The sides of the polygon as pairs of vertices.
+    pub fn edges(&self) -> impl Iterator<Item = (Pos, Pos)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area of the polygon with the shoelace formula, which keeps it an integer.
+    #[must_use]
+    pub fn double_area(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            .unsigned_abs()
+    }
+
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    /// The number of integer points on the sides of the polygon.
+    #[must_use]
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()))
+            .sum()
+    }
+
+    /// The number of integer points strictly inside of the polygon, with Pick's theorem:
+    /// `area = interior + boundary / 2 - 1`. The polygon must not cross itself.
+    #[must_use]
+    pub fn interior_points(&self) -> u64 {
+        (self.double_area() + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    /// The number of integer points inside of the polygon or on its sides.
+    #[must_use]
+    pub fn lattice_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    #[must_use]
+    pub fn on_boundary(&self, pos: Pos) -> bool {
+        self.edges().any(|(a, b)| {
+            let cross = (b.x - a.x) * (pos.y - a.y) - (b.y - a.y) * (pos.x - a.x);
+            cross == 0
+                && (a.x.min(b.x)..=a.x.max(b.x)).contains(&pos.x)
+                && (a.y.min(b.y)..=a.y.max(b.y)).contains(&pos.y)
+        })
+    }
+
+    /// Whether `pos` lies strictly inside of the polygon, points on its sides are not contained.
+    #[must_use]
+    pub fn contains(&self, pos: Pos) -> bool {
+        if self.on_boundary(pos) {
+            return false;
+        }
+
+        // casts a ray to the right and counts the sides it crosses, without leaving the integers.
+        self.edges()
+            .filter(|(a, b)| (a.y > pos.y) != (b.y > pos.y))
+            .filter(|(a, b)| {
+                let dy = b.y - a.y;
+                let lhs = (pos.x - a.x) * dy;
+                let rhs = (pos.y - a.y) * (b.x - a.x);
+                if dy > 0 {
+                    lhs < rhs
+                } else {
+                    lhs > rhs
+                }
+            })
+            .count()
+            % 2
+            == 1
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Polygon;
+    use crate::grid::{Dir, Pos};
+
+    #[test]
+    fn measures_polygons() {
+        // a 4x3 rectangle with a 2x1 notch cut out of its top right corner.
+        let polygon = Polygon::from_moves(
+            Pos::new(0, 0),
+            [
+                (Dir::Right, 2),
+                (Dir::Down, 1),
+                (Dir::Right, 2),
+                (Dir::Down, 2),
+                (Dir::Left, 4),
+                (Dir::Up, 3),
+            ],
+        );
+        assert_eq!(polygon.vertices().len(), 6);
+        assert_eq!(polygon.double_area(), 20);
+        assert_eq!(polygon.area(), 10.0);
+        assert_eq!(polygon.boundary_points(), 14);
+        assert_eq!(polygon.interior_points(), 4);
+        assert_eq!(polygon.lattice_points(), 18);
+
+        let triangle = Polygon::new(vec![Pos::new(0, 0), Pos::new(4, 0), Pos::new(0, 4)]);
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn finds_points_in_polygons() {
+        let polygon = Polygon::new(vec![
+            Pos::new(0, 0),
+            Pos::new(2, 0),
+            Pos::new(2, 1),
+            Pos::new(4, 1),
+            Pos::new(4, 3),
+            Pos::new(0, 3),
+        ]);
+
+        assert!(polygon.contains(Pos::new(1, 1)));
+        assert!(polygon.contains(Pos::new(3, 2)));
+        assert!(!polygon.contains(Pos::new(3, 0)));
+        assert!(!polygon.contains(Pos::new(5, 2)));
+
+        assert!(polygon.on_boundary(Pos::new(3, 1)));
+        assert!(!polygon.contains(Pos::new(3, 1)));
+        assert!(!polygon.on_boundary(Pos::new(3, 2)));
+    }
+}
@@ -0,0 +1,480 @@
+/// A two-dimensional grid with flat storage, and the positions and directions to move around in it.
+use std::fmt::Display;
+use std::ops::{Add, Index, IndexMut};
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+/// A position on a grid, `x` is the column and `y` the row. Positions may lie outside of a grid.
+///
+/// # Display
+/// This value displays as `(x, y)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Pos {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The adjacent position in direction `dir`.
+    #[must_use]
+    pub fn step(self, dir: Dir) -> Self {
+        self + dir.offset()
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The four orthogonally adjacent positions, in the order of [`Dir::ALL`].
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The eight adjacent positions, diagonals included.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Pos::new(dx, dy)))
+            .filter(|offset| *offset != Pos::default())
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Add for Pos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A direction on a grid, `Up` decreases `y`.
+///
+/// # Display
+/// This value displays as an arrow: `^`, `>`, `v` or `<`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The change of position when moving one step in this direction.
+    #[must_use]
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir::Up => Pos::new(0, -1),
+            Dir::Right => Pos::new(1, 0),
+            Dir::Down => Pos::new(0, 1),
+            Dir::Left => Pos::new(-1, 0),
+        }
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    #[must_use]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::Up | Dir::Down)
+    }
+}
+
+impl Display for Dir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Dir::Up => "^",
+            Dir::Right => ">",
+            Dir::Down => "v",
+            Dir::Left => "<",
+        };
+        f.write_str(arrow)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// # Parsing
+/// Grids parse from lines of text, every character is parsed into a cell with the [`FromStr`] of `T`:
+/// ```ignore
+/// # use advent_of_code::grid::{Grid, Pos};
+/// let grid: Grid<u8> = "123\n456".parse().unwrap();
+/// assert_eq!(grid[Pos::new(2, 1)], 6);
+/// assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+/// ```
+///
+/// # Display
+/// This value displays its rows on separate lines, every cell with its own [`Display`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with the value of `f` at every position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos::new(x as i64, y as i64)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from its rows, `None` if the rows have different lengths.
+    #[must_use]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height: if width == 0 { 0 } else { rows.len() },
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (0..self.width as i64).contains(&pos.x) && (0..self.height as i64).contains(&pos.y)
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// The cell at `pos`, `None` if `pos` lies outside of the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height as i64).flat_map(move |y| (0..width as i64).map(move |x| Pos::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell that matches `predicate`, row by row.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a size of zero, grids without columns have no rows either.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// Panics if `x` is not a column of the grid, like [`Grid::row`] for rows.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is out of bounds for a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The adjacent position in direction `dir`, `None` if it lies outside of the grid.
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        Some(pos.step(dir)).filter(|next| self.contains(*next))
+    }
+
+    /// The orthogonally adjacent positions that lie inside of the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors4().filter(|next| self.contains(*next))
+    }
+
+    /// The adjacent positions, diagonals included, that lie inside of the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors8().filter(|next| self.contains(*next))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with `value` in every cell.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::from_fn(width, height, |_| value.clone())
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |p| {
+            self[Pos::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        let last_row = self.height as i64 - 1;
+        Self::from_fn(self.height, self.width, |p| {
+            self[Pos::new(p.y, last_row - p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        let last_col = self.width as i64 - 1;
+        Self::from_fn(self.height, self.width, |p| {
+            self[Pos::new(last_col - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self {
+        let last_col = self.width as i64 - 1;
+        Self::from_fn(self.width, self.height, |p| {
+            self[Pos::new(last_col - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self {
+        let last_row = self.height as i64 - 1;
+        Self::from_fn(self.width, self.height, |p| {
+            self[Pos::new(p.x, last_row - p.y)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `pos` lies outside of the grid.
+    fn index(&self, pos: Pos) -> &T {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!("position {pos} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    /// # Panics
+    /// Panics if `pos` lies outside of the grid.
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("position {pos} is outside of the grid"),
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = s.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let Some(first) = lines.first() else {
+            return Err(ParseError::at(s, s, "expected a grid"));
+        };
+
+        let width = first.chars().count();
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for line in &lines {
+            let mut count = 0;
+
+            for (i, c) in line.char_indices() {
+                let cell = c
+                    .to_string()
+                    .parse()
+                    .map_err(|_| ParseError::at(s, &line[i..], format!("invalid cell `{c}`")))?;
+                cells.push(cell);
+                count += 1;
+            }
+
+            if count != width {
+                let message = format!("expected {width} cells, found {count}");
+                return Err(ParseError::at(s, line, message));
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Dir, Grid, Pos};
+
+    fn get_grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = get_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(1, 1)], 'e');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.find(|c| *c == 'f'), Some(Pos::new(2, 1)));
+    }
+
+    #[test]
+    fn rejects_invalid_grids() {
+        let error = "12\n3x\n".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "invalid cell `x`");
+
+        let error = "12\n3\n".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!(error.message, "expected 2 cells, found 1");
+
+        assert!("\n".parse::<Grid<u8>>().is_err());
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![2, 3]]), None);
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = get_grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = get_grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_columns_out_of_bounds() {
+        let _ = get_grid().column(3);
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let grid = get_grid();
+        let corner = Pos::new(0, 0);
+        assert_eq!(
+            grid.neighbors4(corner).collect::<Vec<_>>(),
+            vec![Pos::new(1, 0), Pos::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors8(corner).count(), 3);
+        assert_eq!(Pos::new(5, 5).neighbors8().count(), 8);
+        assert_eq!(grid.step(corner, Dir::Left), None);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Left.reverse(), Dir::Right);
+    }
+}
@@ -0,0 +1,257 @@
+/// Sets of integers stored as ranges, and piecewise mappings of them, for puzzles whose inputs
+/// are too large to handle number by number.
+use std::ops::Range;
+
+/// A set of integers, stored as sorted, disjoint and non-adjacent half-open ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the integers of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let start = self.ranges.partition_point(|r| r.end < range.start);
+        let end = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if start == end {
+            range
+        } else {
+            self.ranges[start].start.min(range.start)..self.ranges[end - 1].end.max(range.end)
+        };
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    /// The ranges of the set, in increasing order.
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end.abs_diff(r.start)).sum()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(index).is_some_and(|r| r.contains(&value))
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            // the range that ends first can't overlap anything else.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// The integers of `self` that aren't in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            while let Some(hole) = others.peek() {
+                if hole.end <= start {
+                    others.next();
+                    continue;
+                }
+                if hole.start >= range.end {
+                    break;
+                }
+
+                if hole.start > start {
+                    ranges.push(start..hole.start);
+                }
+                start = hole.end;
+                if hole.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        RangeSet { ranges }
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A piecewise-linear mapping of integers: integers of a source range are shifted to a destination
+/// range of the same length, integers outside of every source range map to themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Source ranges with the offset added to their integers, the first match wins.
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl RangeMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a mapping from `(destination, source, length)` rows. Earlier rows take precedence.
+    pub fn from_table(table: impl IntoIterator<Item = (i64, i64, i64)>) -> Self {
+        let mut map = RangeMap::new();
+        for (destination, source, length) in table {
+            map.insert(destination, source, length);
+        }
+        map
+    }
+
+    /// Maps `source..source + length` to `destination..destination + length`,
+    /// unless integers are already mapped by an earlier call.
+    pub fn insert(&mut self, destination: i64, source: i64, length: i64) {
+        self.pieces
+            .push((source..source + length, destination - source));
+    }
+
+    /// The image of a single integer.
+    #[must_use]
+    pub fn get(&self, value: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// The image of every integer of `set`.
+    #[must_use]
+    pub fn map(&self, set: &RangeSet) -> RangeSet {
+        let mut unmapped = set.clone();
+        let mut image = RangeSet::new();
+
+        for (source, offset) in &self.pieces {
+            let source = RangeSet::from(source.clone());
+            for range in unmapped.intersection(&source).ranges() {
+                image.insert(range.start + offset..range.end + offset);
+            }
+            unmapped = unmapped.difference(&source);
+        }
+
+        image.union(&unmapped)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{RangeMap, RangeSet};
+
+    #[test]
+    fn merges_ranges() {
+        let set: RangeSet = [5..8, 1..3, 3..4, 10..10, 7..9].into_iter().collect();
+        assert_eq!(set.ranges(), [1..4, 5..9]);
+        assert_eq!(set.len(), 7);
+        assert_eq!((set.min(), set.max()), (Some(1), Some(8)));
+        assert!(set.contains(3) && !set.contains(4) && !set.contains(9));
+
+        let mut set = set;
+        set.insert(0..6);
+        assert_eq!(set, RangeSet::from(0..9));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: RangeSet = [0..10, 20..30].into_iter().collect();
+        let b: RangeSet = [5..25, 28..29, 40..50].into_iter().collect();
+
+        assert_eq!(a.union(&b).ranges(), [0..30, 40..50]);
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..29]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28, 29..30]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 40..50]);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn maps_ranges() {
+        let map = RangeMap::from_table([(50, 98, 2), (52, 50, 48)]);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(53), 55);
+        assert_eq!(map.get(10), 10);
+
+        let seeds: RangeSet = [79..93, 55..68].into_iter().collect();
+        assert_eq!(map.map(&seeds).ranges(), [57..70, 81..95]);
+
+        let edges = RangeSet::from(45..100);
+        assert_eq!(map.map(&edges), edges);
+        assert_eq!(map.map(&RangeSet::from(97..99)).ranges(), [50..51, 99..100]);
+    }
+}
@@ -1,4 +1,15 @@
+pub mod cycles;
 mod day;
+pub mod geometry;
+pub mod grid;
+pub mod intervals;
+pub mod math;
+pub mod parse;
+pub mod search;
 pub mod template;
+pub mod trace;
+pub mod viz;
+mod year;
 
 pub use day::*;
+pub use year::*;
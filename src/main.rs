@@ -1,55 +1,139 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, verify};
 use args::{parse, AppArguments};
 
+/// Solutions that are linked into this binary, generated by `build.rs`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use std::process;
+    use std::{env, ffi::OsString, process, time::Duration};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{
+            record::Format,
+            runner::{flag_value, parse_timeout},
+        },
+        trace, viz, Day, Puzzle, Year,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             time: bool,
             submit: Option<u8>,
+            force: bool,
+            format: Format,
+            timeout: Option<Duration>,
+            visualize: Option<viz::Output>,
+            trace: Option<trace::Parts>,
+            trace_log: bool,
+            /// A file, a directory of inputs or `-` for stdin, instead of the account's input.
+            input: Option<String>,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            timeout: Option<Duration>,
         },
         All {
-            release: bool,
+            /// `None` runs every year.
+            year: Option<Year>,
             time: bool,
+            format: Format,
+            compare_threshold: Option<f64>,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
     }
 
+    /// Takes a flag with an optional value (`--flag` or `--flag=value`) out of `raw`,
+    /// pico-args only understands flags whose value is the next argument.
+    fn take_flag(raw: &mut Vec<String>, flag: &str) -> Option<Option<String>> {
+        let value = flag_value(raw, flag).map(|value| value.map(String::from));
+        raw.retain(|arg| flag_value(std::slice::from_ref(arg), flag).is_none());
+        value
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut raw: Vec<String> = env::args().skip(1).collect();
+        let visualize = take_flag(&mut raw, "--visualize")
+            .map(|value| value.map_or(Ok(viz::Output::default()), |v| v.parse()))
+            .transpose()?;
+        let trace = take_flag(&mut raw, "--trace")
+            .map(|value| value.map_or(Ok(trace::Parts::Both), |v| v.parse()))
+            .transpose()?;
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+        let mut args =
+            pico_args::Arguments::from_vec(raw.into_iter().map(OsString::from).collect());
+
+        let subcommand = args.subcommand()?;
+
+        // `--year` is accepted by every subcommand, `AOC_YEAR` provides the default.
+        let year = args
+            .opt_value_from_str::<_, Year>("--year")?
+            .or_else(Year::from_env)
+            .ok_or("no year given. Pass `--year <year>` or set AOC_YEAR in .cargo/config.toml.");
+
+        // parts that run longer than `--timeout` seconds are reported as timed out.
+        let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let all_years = args.contains("--all-years");
+
+                // `all` runs in-process, the build profile of the binary decides whether it's optimized.
+                if args.contains("--release") {
+                    eprintln!("Warning: --release has no effect on `all`, `cargo all` always runs an optimized build.");
+                }
+
+                AppArguments::All {
+                    year: if all_years { None } else { Some(year?) },
+                    time: args.contains("--time"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    compare_threshold: compare.then_some(threshold),
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    timeout,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                year: year?,
+                day: args.opt_free_from_str()?,
+                timeout,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year?, args.free_from_str()?),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year?, args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year?, args.free_from_str()?),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::new(year?, args.free_from_str()?),
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
                 time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                timeout,
+                visualize,
+                trace,
+                trace_log: args.contains("--trace-log"),
+                input: args.opt_value_from_str("--input")?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -61,6 +145,13 @@ mod args {
             }
         };
 
+        if visualize.is_some() && !matches!(app_args, AppArguments::Solve { .. }) {
+            eprintln!("Warning: --visualize is only supported by `solve`.");
+        }
+        if trace.is_some() && !matches!(app_args, AppArguments::Solve { .. }) {
+            eprintln!("Warning: --trace is only supported by `solve`.");
+        }
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -77,16 +168,44 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                time,
+                format,
+                compare_threshold,
+                jobs,
+                timeout,
+            } => all::handle(
+                solutions::SOLUTIONS,
+                year,
+                time,
+                format,
+                compare_threshold,
+                jobs,
+                timeout,
+            ),
+            AppArguments::Verify { year, day, timeout } => {
+                verify::handle(solutions::SOLUTIONS, year, day, timeout);
+            }
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold { puzzle } => scaffold::handle(puzzle),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                force,
+                format,
+                timeout,
+                visualize,
+                trace,
+                trace_log,
+                input,
+            } => solve::handle(
+                puzzle, release, time, submit, force, format, timeout, visualize, trace, trace_log,
+                input,
+            ),
         },
     };
 }
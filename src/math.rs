@@ -0,0 +1,220 @@
+/// Number theory helpers: greatest common divisors, least common multiples,
+/// the Chinese remainder theorem and integer solutions of quadratic inequalities.
+use std::ops::{Add, Div, Mul, Neg, RangeInclusive, Rem, Sub};
+
+/// The primitive integers.
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+/// The primitive signed integers.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {
+        integer!($($t),*);
+        $(impl Signed for $t {})*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize);
+signed!(i8, i16, i32, i64, i128, isize);
+
+/* -------------------------------------------------------------------------- */
+
+/// The greatest common divisor of `a` and `b`, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    if a < T::ZERO {
+        T::ZERO - a
+    } else {
+        a
+    }
+}
+
+/// The least common multiple of `a` and `b`, `None` if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    let lcm = (a / gcd(a, b)).checked_mul(b)?;
+    Some(if lcm < T::ZERO { T::ZERO - lcm } else { lcm })
+}
+
+/// The least common multiple of all `numbers`, 1 if there are none and `None` if it overflows.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |acc, number| lcm(acc, number))
+}
+
+/// Extended Euclidean algorithm, returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulus`, `None` if they aren't coprime.
+pub fn mod_inverse<T: Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a, modulus);
+    (g == T::ONE).then(|| ((x % modulus) + modulus) % modulus)
+}
+
+/// Chinese remainder theorem: the solutions of `x ≡ residue (mod modulus)` for every
+/// `(residue, modulus)` pair, as `(x, m)` such that every solution is `x + k * m` with `0 <= x < m`.
+/// The moduli must be positive but don't have to be coprime. `None` if the congruences contradict
+/// each other or if `m` overflows.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (x, m) = congruences
+        .into_iter()
+        .map(|(residue, modulus)| (i128::from(residue), i128::from(modulus)))
+        .try_fold((0, 1), combine_congruences)?;
+
+    Some((x as i64, m as i64))
+}
+
+/// Merges `x ≡ a (mod m)` and `x ≡ b (mod n)`. Products of the i64 moduli fit in an i128.
+fn combine_congruences((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m, n);
+
+    let diff = b - a;
+    if diff % g != 0 {
+        return None;
+    }
+
+    // `p` is the inverse of m / g modulo n / g.
+    let k = (diff / g % (n / g)) * p % (n / g);
+    let lcm = m / g * n;
+    i64::try_from(lcm).ok()?;
+
+    Some(((a + m * k).rem_euclid(lcm), lcm))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The integers `x` for which `a * x² + b * x + c < 0`, computed without floating points.
+/// `a` must be positive, the range is empty if there are no such integers.
+pub fn quadratic_below_zero(a: i64, b: i64, c: i64) -> RangeInclusive<i64> {
+    assert!(a > 0, "the quadratic must open upwards");
+    let (a, b, c) = (i128::from(a), i128::from(b), i128::from(c));
+    let f = |x: i128| a * x * x + b * x + c;
+
+    let discriminant = b * b - 4 * a * c;
+    if discriminant <= 0 {
+        #[allow(clippy::reversed_empty_ranges)]
+        return 1..=0;
+    }
+
+    // the roots lie between these candidates, which are moved inwards until they're below zero.
+    let root = discriminant.isqrt();
+    let mut lower = (-b - root).div_euclid(2 * a) - 1;
+    let mut upper = (-b + root + 1).div_euclid(2 * a) + 1;
+    while lower <= upper && f(lower) >= 0 {
+        lower += 1;
+    }
+    while upper >= lower && f(upper) >= 0 {
+        upper -= 1;
+    }
+
+    lower as i64..=upper as i64
+}
+
+/// The number of integers in `range`.
+pub fn range_len(range: &RangeInclusive<i64>) -> u64 {
+    if range.is_empty() {
+        0
+    } else {
+        range.end().abs_diff(*range.start()) + 1
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{
+        crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse, quadratic_below_zero, range_len,
+    };
+
+    #[test]
+    fn computes_divisors_and_multiples() {
+        assert_eq!(gcd(12_u32, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(0_u8, 0), 0);
+
+        assert_eq!(lcm(4_u8, 6), Some(12));
+        assert_eq!(lcm(-4_i32, 6), Some(12));
+        assert_eq!(lcm(200_u8, 3), None);
+        assert_eq!(lcm_all([2_usize, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u64>::new()), Some(1));
+
+        assert_eq!(extended_gcd(240_i32, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3_i64, 7), Some(5));
+        assert_eq!(mod_inverse(4_i64, 6), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // moduli that aren't coprime.
+        assert_eq!(crt([(3, 6), (1, 4)]), Some((9, 12)));
+        assert_eq!(crt([(0, 6), (1, 4)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(i64::MAX - 1, i64::MAX), (0, 2)]), None);
+    }
+
+    #[test]
+    fn solves_quadratics() {
+        // x * (7 - x) > 9
+        assert_eq!(quadratic_below_zero(1, -7, 9), 2..=5);
+        // x * (30 - x) > 200, the roots 10 and 20 are excluded.
+        assert_eq!(quadratic_below_zero(1, -30, 200), 11..=19);
+        assert!(quadratic_below_zero(1, 0, 1).is_empty());
+        // the roots are 0.2 and 0.8.
+        assert!(quadratic_below_zero(25, -25, 4).is_empty());
+        assert_eq!(range_len(&quadratic_below_zero(1, -71530, 940200)), 71503);
+    }
+}
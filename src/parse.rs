@@ -0,0 +1,356 @@
+/// Combinators for the recurring shapes of puzzle inputs, and helpers that run nom parsers
+/// to completion and report failures with their position in the input.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{
+        char, digit1, line_ending, multispace0, none_of, one_of, space0, space1,
+    },
+    combinator::{map_opt, opt, recognize},
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated},
+    IResult,
+};
+
+/// An error at a position of a puzzle input.
+///
+/// # Display
+/// This value displays the position, the message and the offending line:
+/// ```text
+/// line 2, column 6: expected digit
+///   Game x: 3 blue
+///        ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the error.
+    pub line: usize,
+    /// 1-based column of the error, in characters.
+    pub column: usize,
+    /// The text of the offending line.
+    pub context: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error at the start of `position`, which must be a slice of `input`.
+    /// Positions outside of `input` are reported at its end.
+    pub fn at(input: &str, position: &str, message: impl Into<String>) -> Self {
+        let offset = (position.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            context: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Converts a nom error of a parser that ran on (a slice of) `input`.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => {
+                Self::at(input, &input[input.len()..], "unexpected end of input")
+            }
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(input, e.input, describe(e.code)),
+        }
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        // parsers that convert their output fail if the value is out of range.
+        ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "invalid value".into(),
+        ErrorKind::Eof => "expected end of input".into(),
+        kind => format!("expected {}", kind.description().to_lowercase()),
+    }
+}
+
+impl Error for ParseError {}
+
+/// Lets solution parts that return `Result<T, String>` use `?` on parse results.
+impl From<ParseError> for String {
+    fn from(e: ParseError) -> Self {
+        e.to_string()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.context)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Runs `parser` on the whole `input`. Fails if input other than trailing whitespace remains.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    let (rest, value) = parser(input).map_err(|e| ParseError::from_nom(input, e))?;
+
+    if rest.trim().is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::at(input, rest, "unexpected trailing input"))
+    }
+}
+
+/// Runs `parser` on every non-empty line of `input`. Each line has to be consumed completely.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (rest, value) = parser(line).map_err(|e| ParseError::from_nom(input, e))?;
+
+            if rest.trim().is_empty() {
+                Ok(value)
+            } else {
+                Err(ParseError::at(input, rest, "unexpected trailing input"))
+            }
+        })
+        .collect()
+}
+
+/// Runs `parser` on every paragraph of `input`, see [`paragraphs`].
+/// Each paragraph has to be consumed completely.
+pub fn parse_paragraphs<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    paragraphs(input)
+        .map(|paragraph| {
+            let (rest, value) = parser(paragraph).map_err(|e| ParseError::from_nom(input, e))?;
+
+            if rest.trim().is_empty() {
+                Ok(value)
+            } else {
+                Err(ParseError::at(input, rest, "unexpected trailing input"))
+            }
+        })
+        .collect()
+}
+
+/// Splits `input` into its blocks of lines that are separated by blank lines.
+/// The paragraphs are slices of `input` without their final line break.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut paragraphs = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                paragraphs.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        paragraphs.push(&input[start..end]);
+    }
+
+    paragraphs.into_iter()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An unsigned integer, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    number(digit1, input)
+}
+
+/// An integer with an optional sign, e.g. `-42` or `+42`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    number(recognize(pair(opt(one_of("+-")), digit1)), input)
+}
+
+/// Converts the digits that `digits` recognized. Numbers that don't fit into `T` are a failure,
+/// so that they are reported instead of ending a list early.
+fn number<'a, T: FromStr>(
+    mut digits: impl FnMut(&'a str) -> IResult<&'a str, &'a str>,
+    input: &'a str,
+) -> IResult<&'a str, T> {
+    let (rest, digits) = digits(input)?;
+
+    match digits.trim_start_matches('+').parse() {
+        Ok(number) => Ok((rest, number)),
+        Err(_) => Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            ErrorKind::MapRes,
+        ))),
+    }
+}
+
+/// Integers that are separated by spaces on a single line, e.g. `79 14  -55 13`.
+pub fn numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, signed)(input)
+}
+
+/// A `key: value` pair, where `key` is anything up to the colon and `value` may start on the next line.
+///
+/// ```ignore
+/// # use advent_of_code::parse::{key_value, numbers};
+/// let (_, (key, values)) = key_value(numbers::<u32>)("seed-to-soil map:\n50 98 2").unwrap();
+/// assert_eq!((key, values), ("seed-to-soil map", vec![50, 98, 2]));
+/// ```
+pub fn key_value<'a, T>(
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, T)> {
+    separated_pair(
+        take_while1(|c| c != ':' && c != '\n'),
+        terminated(char(':'), multispace0),
+        value,
+    )
+}
+
+/// A `value` that is labeled by `key`, e.g. `labeled("Time", numbers)` for `Time:  7  15  30`.
+pub fn labeled<'a, T>(
+    key: &'static str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    preceded(terminated(tag(key), pair(char(':'), multispace0)), value)
+}
+
+/// One or more values on consecutive lines.
+pub fn lines<'a, T>(
+    value: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(pair(space0, line_ending), value)
+}
+
+/// The rows of a grid of characters, `cell` converts each character and rejects unknown ones.
+/// Rows may have different lengths.
+pub fn grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Vec<T>>> {
+    move |input| lines(many1(map_opt(none_of("\r\n"), &mut cell)))(input)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use nom::{bytes::complete::tag, character::complete::u32, sequence::preceded};
+
+    use super::{
+        grid, key_value, labeled, lines, numbers, paragraphs, parse_all, parse_lines,
+        parse_paragraphs, signed, ParseError,
+    };
+
+    #[test]
+    fn locates_errors() {
+        let input = "Game 1\nGame x";
+        let error = ParseError::at(input, &input[12..], "expected digit");
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.context, "Game x");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected digit\n  Game x\n       ^"
+        );
+    }
+
+    #[test]
+    fn parses_lines() {
+        let parser = |line| preceded(tag("Game "), u32)(line);
+        assert_eq!(parse_lines("Game 1\n\nGame 2\n", parser), Ok(vec![1, 2]));
+
+        let error = parse_lines("Game 1\nGame x\n", parser).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.message, "expected digit");
+
+        let error = parse_lines("Game 1\nGame 2 blue\n", parser).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.message, "unexpected trailing input");
+    }
+
+    #[test]
+    fn parses_all() {
+        assert_eq!(parse_all("Game 1\n", preceded(tag("Game "), u32)), Ok(1));
+
+        let error = parse_all("Game", preceded(tag("Game "), u32)).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected tag");
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(signed::<i32>("-42 "), Ok((" ", -42)));
+        assert_eq!(signed::<i32>("+7"), Ok(("", 7)));
+        assert_eq!(
+            numbers::<i64>("79 14  -55\n13"),
+            Ok(("\n13", vec![79, 14, -55]))
+        );
+
+        let error = parse_all("1 2 -3", numbers::<u32>).unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (5, "invalid value"));
+    }
+
+    #[test]
+    fn parses_labeled_blocks() {
+        let input = "Time:  7 15\nseed-to-soil map:\n50 98 2\n52 50 48\n\nnext";
+        let (rest, times) = labeled("Time", numbers::<u32>)(input).unwrap();
+        assert_eq!(times, vec![7, 15]);
+
+        let (rest, (key, ranges)) = key_value(lines(numbers::<u32>))(&rest[1..]).unwrap();
+        assert_eq!(key, "seed-to-soil map");
+        assert_eq!(ranges, vec![vec![50, 98, 2], vec![52, 50, 48]]);
+        assert_eq!(rest, "\n\nnext");
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        let input = "a\nb\n\n  \nc\r\n\n";
+        assert_eq!(paragraphs(input).collect::<Vec<_>>(), vec!["a\nb", "c"]);
+
+        let error = parse_paragraphs(input, |p| tag("a\nb")(p)).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+
+    #[test]
+    fn parses_grids() {
+        let cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        assert_eq!(
+            parse_all("#.\n.#\n", grid(cell)),
+            Ok(vec![vec![true, false], vec![false, true]])
+        );
+
+        let error = parse_all("#.\n.x\n", grid(cell)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
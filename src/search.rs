@@ -0,0 +1,327 @@
+/// Graph searches over states whose successors are computed by closures: BFS, Dijkstra and A*.
+use std::cmp::Reverse;
+use std::collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path from one of the start states to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+/// The outcome of a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<S, C> {
+    /// The cheapest path to a goal, `None` if no goal is reachable.
+    pub path: Option<Path<S, C>>,
+    /// Number of states that were expanded, useful to compare heuristics.
+    pub visited: usize,
+}
+
+impl<S, C: Copy> Search<S, C> {
+    /// The cost of the path to the goal, `None` if no goal is reachable.
+    pub fn cost(&self) -> Option<C> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// States that were discovered by a search, with their parent to reconstruct paths.
+struct Discovered<S, C> {
+    states: Vec<S>,
+    /// Index of every state in `states`.
+    indices: HashMap<S, usize>,
+    /// The best cost and the parent of every state, by index.
+    info: Vec<(C, Option<usize>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Discovered<S, C> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            indices: HashMap::new(),
+            info: vec![],
+        }
+    }
+
+    /// Records `state` if it's new or reached at a better cost, returns its index in that case.
+    fn offer(
+        &mut self,
+        state: S,
+        cost: C,
+        parent: Option<usize>,
+        is_better: impl Fn(C, C) -> bool,
+    ) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if is_better(cost, self.info[index].0) {
+                    self.info[index] = (cost, parent);
+                    Some(index)
+                } else {
+                    None
+                }
+            }
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(index);
+                self.info.push((cost, parent));
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Path<S, C> {
+        let cost = self.info[index].0;
+        let mut states = vec![self.states[index].clone()];
+
+        while let Some(parent) = self.info[index].1 {
+            states.push(self.states[parent].clone());
+            index = parent;
+        }
+
+        states.reverse();
+        Path { states, cost }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Breadth-first search from every state of `starts` to the first state that satisfies `is_goal`.
+/// The cost of the path is its number of steps.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut discovered = Discovered::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(index) = discovered.offer(start, 0, None, |_, _| false) {
+            queue.push_back(index);
+        }
+    }
+
+    let mut visited = 0;
+
+    while let Some(index) = queue.pop_front() {
+        visited += 1;
+        let state = discovered.states[index].clone();
+
+        if is_goal(&state) {
+            return Search {
+                path: Some(discovered.path(index)),
+                visited,
+            };
+        }
+
+        let steps = discovered.info[index].0 + 1;
+        for next in successors(&state) {
+            if let Some(next) = discovered.offer(next, steps, Some(index), |_, _| false) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Search {
+        path: None,
+        visited,
+    }
+}
+
+/// The number of steps to every state that is reachable from `starts`, the start states included.
+pub fn reachable<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if steps.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let next_steps = steps[&state] + 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = steps.entry(next.clone()) {
+                entry.insert(next_steps);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    steps
+}
+
+/// Dijkstra's algorithm from every state of `starts` to the cheapest state that satisfies `is_goal`.
+/// `successors` returns the next states together with the cost of the step, which must not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search from every state of `starts` to the cheapest state that satisfies `is_goal`.
+/// `heuristic` estimates the remaining cost to a goal and must never overestimate it,
+/// otherwise the returned path may not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut discovered = Discovered::new();
+    // entries are (estimated total cost, cost so far, index), stale entries are skipped when popped.
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = discovered.offer(start, C::default(), None, |a, b| a < b) {
+            heap.push(Reverse((estimate, C::default(), index)));
+        }
+    }
+
+    let mut visited = 0;
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > discovered.info[index].0 {
+            continue;
+        }
+
+        visited += 1;
+        let state = discovered.states[index].clone();
+
+        if is_goal(&state) {
+            return Search {
+                path: Some(discovered.path(index)),
+                visited,
+            };
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+
+            if let Some(next) = discovered.offer(next, next_cost, Some(index), |a, b| a < b) {
+                heap.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    Search {
+        path: None,
+        visited,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{astar, bfs, dijkstra, reachable};
+    use crate::grid::{Grid, Pos};
+
+    fn get_maze() -> Grid<char> {
+        "S.#.\n.##.\n...G".parse().unwrap()
+    }
+
+    fn open_neighbors(maze: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        maze.neighbors4(pos).filter(|p| maze[*p] != '#').collect()
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let maze = get_maze();
+        let goal = Pos::new(3, 2);
+
+        let search = bfs(
+            [Pos::new(0, 0)],
+            |p| open_neighbors(&maze, *p),
+            |p| *p == goal,
+        );
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(path.states.first(), Some(&Pos::new(0, 0)));
+        assert_eq!(path.states.last(), Some(&goal));
+
+        let search = bfs(
+            [Pos::new(0, 0)],
+            |p| open_neighbors(&maze, *p),
+            |p| *p == Pos::new(2, 0),
+        );
+        assert_eq!(search.path, None);
+        assert_eq!(search.visited, 9);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // walking right costs 10, every other step costs 1.
+        let successors = |p: &Pos| {
+            let maze = get_maze();
+            open_neighbors(&maze, *p)
+                .into_iter()
+                .map(|n| (n, if n.x > p.x { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let goal = Pos::new(3, 2);
+
+        let search = dijkstra([Pos::new(0, 0)], successors, |p| *p == goal);
+        assert_eq!(search.cost(), Some(32));
+
+        let informed = astar(
+            [Pos::new(0, 0)],
+            successors,
+            |p| p.manhattan(goal),
+            |p| *p == goal,
+        );
+        assert_eq!(informed.cost(), Some(32));
+        assert!(informed.visited <= search.visited);
+    }
+
+    #[test]
+    fn starts_anywhere() {
+        let maze = get_maze();
+        let starts = [Pos::new(0, 0), Pos::new(3, 0)];
+
+        let search = dijkstra(
+            starts,
+            |p| open_neighbors(&maze, *p).into_iter().map(|n| (n, 1)),
+            |p| *p == Pos::new(3, 2),
+        );
+        assert_eq!(search.cost(), Some(2));
+        assert_eq!(search.path.unwrap().states[0], Pos::new(3, 0));
+
+        let steps = reachable(starts, |p| open_neighbors(&maze, *p));
+        assert_eq!(steps.len(), 9);
+        assert_eq!(steps[&Pos::new(1, 2)], 3);
+    }
+}
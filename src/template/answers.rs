@@ -0,0 +1,200 @@
+/// Module that stores the known correct answers of every puzzle in `data/YYYY/answers/DD.txt`.
+///
+/// An answers file contains one line per solved part:
+/// ```text
+/// part_one: 142
+/// part_two: 281
+/// ```
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::get_data_path;
+use crate::Puzzle;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The recorded answers of a day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// The outcome of comparing an answer against the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// No answer has been recorded for this part yet.
+    Unknown,
+}
+
+impl Verdict {
+    /// `Some(true)` for correct answers, `Some(false)` for incorrect ones and `None` if unknown.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Verdict::Correct => Some(true),
+            Verdict::Incorrect { .. } => Some(false),
+            Verdict::Unknown => None,
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path("answers", puzzle, "txt")
+}
+
+impl Answers {
+    /// Reads the answers of a puzzle. A missing file is treated as no recorded answers.
+    pub fn load(puzzle: Puzzle) -> Result<Self, Error> {
+        let path = get_path(puzzle);
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Writes the answers of a puzzle, creating `data/YYYY/answers` if needed.
+    pub fn save(&self, puzzle: Puzzle) -> Result<(), Error> {
+        let path = get_path(puzzle);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.into()),
+            2 => self.part_two = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    /// Compares an answer of a part against the recorded one.
+    #[must_use]
+    pub fn verify(&self, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer.trim() => Verdict::Correct,
+            (Some(expected), _) => Verdict::Incorrect {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+
+        for line in s.lines().filter(|l| !l.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| Error::Parser(format!("Malformed answer line: {line}")))?;
+
+            match key.trim() {
+                "part_one" => answers.part_one = Some(value.trim().into()),
+                "part_two" => answers.part_two = Some(value.trim().into()),
+                key => return Err(Error::Parser(format!("Unknown answer key: {key}"))),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(answer) = &self.part_one {
+            writeln!(f, "part_one: {answer}")?;
+        }
+        if let Some(answer) = &self.part_two {
+            writeln!(f, "part_two: {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_answers() {
+        let answers: Answers = "part_one: 142\n\npart_two:  281 \n".parse().unwrap();
+        assert_eq!(answers.get(1), Some("142"));
+        assert_eq!(answers.get(2), Some("281"));
+        assert_eq!(answers.to_string(), "part_one: 142\npart_two: 281\n");
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers: Answers = "part_two: 281".parse().unwrap();
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.to_string(), "part_two: 281\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_unknown_keys() {
+        "part_three: 1".parse::<Answers>().unwrap();
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers: Answers = "part_one: 142".parse().unwrap();
+        assert_eq!(answers.verify(1, Some("142")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, Some("143")),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(1, None),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(answers.verify(2, Some("281")), Verdict::Unknown);
+    }
+}
@@ -1,10 +1,14 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
-    process::{Command, Output, Stdio},
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::template::aoc_client::{self, AocClient, Error as ClientError, NativeClient};
+use crate::Puzzle;
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -35,77 +39,136 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+/// The aoc-cli backend of [`AocClient`]. Requires `aoc` to be installed.
+pub struct AocCli;
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
-
-    call_aoc_cli(&args)
+impl AocCli {
+    pub fn new() -> Result<Self, AocCommandError> {
+        check()?;
+        Ok(Self)
+    }
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
-
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
-
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+impl AocClient for AocCli {
+    fn name(&self) -> &'static str {
+        "aoc-cli"
+    }
+
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, ClientError> {
+        let path = get_temp_path(puzzle, "input");
+        let args = build_args(
+            "download",
+            &[
+                "--overwrite".into(),
+                "--input-only".into(),
+                "--input-file".into(),
+                path.display().to_string(),
+            ],
+            puzzle,
+        );
+
+        call_aoc_cli(&args)?;
+        read_temp_file(&path)
+    }
+
+    /// aoc-cli only writes its own markdown, which [`puzzle::suggest_examples`](crate::template::puzzle::suggest_examples)
+    /// reads less reliably (e.g. emphasized answers). The page is fetched directly with the session cookie
+    /// of aoc-cli and converted like [`NativeClient`] does, the markdown of aoc-cli is the fallback.
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, ClientError> {
+        if let Some(description) =
+            get_session().and_then(|session| NativeClient::new(session).fetch_puzzle(puzzle).ok())
+        {
+            return Ok(description);
+        }
+
+        let path = get_temp_path(puzzle, "puzzle");
+        let args = build_args(
+            "download",
+            &[
+                "--overwrite".into(),
+                "--puzzle-only".into(),
+                "--puzzle-file".into(),
+                path.display().to_string(),
+            ],
+            puzzle,
+        );
+
+        call_aoc_cli(&args)?;
+        read_temp_file(&path)
+    }
+
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, ClientError> {
+        let output = submit(puzzle, part, answer)?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
+/// The session cookie that aoc-cli would use: the one of [`aoc_client::get_session`], `ADVENT_OF_CODE_SESSION`
+/// or `adventofcode.session` in the config directory.
+fn get_session() -> Option<String> {
+    if let Some(session) = aoc_client::get_session() {
+        return Some(session);
+    }
+
+    let session = match env::var("ADVENT_OF_CODE_SESSION") {
+        Ok(session) => session,
+        Err(_) => {
+            let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+                Some(dir) => PathBuf::from(dir),
+                None => Path::new(&env::var_os("HOME")?).join(".config"),
+            };
+            fs::read_to_string(config_dir.join("adventofcode.session")).ok()?
+        }
+    };
+
+    aoc_client::parse_session(&session)
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+fn get_temp_path(puzzle: Puzzle, kind: &str) -> PathBuf {
+    env::temp_dir().join(format!(
+        "aoc-{}-{}-{kind}",
+        process::id(),
+        puzzle.bin_name()
+    ))
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn read_temp_file(path: &Path) -> Result<String, ClientError> {
+    let contents = fs::read_to_string(path).map_err(|_| AocCommandError::IoError)?;
+    let _ = fs::remove_file(path);
+    Ok(contents)
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+pub fn submit(puzzle: Puzzle, part: u8, result: &str) -> Result<Output, AocCommandError> {
+    // workaround: the argument order is inverted for submit.
+    let mut args = build_args("submit", &[], puzzle);
+    args.push(part.to_string());
+    args.push(result.to_string());
+
+    // capture the response so the runner can interpret it.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
     }
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: Puzzle) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
@@ -0,0 +1,391 @@
+/// Backends that talk to adventofcode.com on behalf of the `download`, `read` and `solve --submit` commands.
+///
+/// The backend is picked with the `AOC_CLIENT` environment variable:
+/// - `native`: talks to the website directly, using the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+/// - `aoc-cli`: shells out to the `aoc` command-line tool.
+/// - `fake`: serves inputs, puzzles and answers from the directory in `AOC_FAKE_DIR`, for offline testing.
+///
+/// Without `AOC_CLIENT`, the native backend is used if a session cookie is configured and aoc-cli otherwise.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::answers::Answers;
+use crate::template::aoc_cli::{AocCli, AocCommandError};
+use crate::template::puzzle;
+use crate::Puzzle;
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum Error {
+    AocCli(AocCommandError),
+    Http(String),
+    Config(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<AocCommandError> for Error {
+    fn from(e: AocCommandError) -> Self {
+        Error::AocCli(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::AocCli(e) => write!(f, "failed to call aoc-cli: {e}"),
+            Error::Http(s) | Error::Config(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A way of fetching puzzles and submitting answers.
+pub trait AocClient {
+    /// Human-readable name of the backend.
+    fn name(&self) -> &'static str;
+
+    /// Returns the personal input of a puzzle.
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, Error>;
+
+    /// Returns the description of a puzzle as Markdown, including part two once it is unlocked.
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, Error>;
+
+    /// Submits an answer and returns the text of the server's response.
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, Error>;
+}
+
+/// Creates the backend selected by the environment.
+pub fn from_env() -> Result<Box<dyn AocClient>, Error> {
+    match env::var("AOC_CLIENT").as_deref() {
+        Ok("native") => Ok(Box::new(NativeClient::from_env()?)),
+        Ok("aoc-cli") => Ok(Box::new(AocCli::new()?)),
+        Ok("fake") => Ok(Box::new(FakeClient::from_env())),
+        Ok(x) => Err(Error::Config(format!(
+            "unknown AOC_CLIENT \"{x}\", expecting one of `native`, `aoc-cli` or `fake`."
+        ))),
+        Err(_) => match NativeClient::from_env() {
+            Ok(client) => Ok(Box::new(client)),
+            Err(_) => AocCli::new().map(|c| Box::new(c) as Box<dyn AocClient>).map_err(|_| {
+                Error::Config("no session cookie found. Set AOC_SESSION or create \"~/.adventofcode.session\", or install aoc-cli via \"cargo install aoc-cli\".".into())
+            }),
+        },
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `~/.adventofcode.session`.
+pub fn get_session() -> Option<String> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(get_session_path()?).ok()?,
+    };
+
+    parse_session(&session)
+}
+
+fn get_session_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(Path::new(&home).join(".adventofcode.session"))
+}
+
+/// Accepts both the raw cookie value and a copied `session=...` pair.
+pub(crate) fn parse_session(s: &str) -> Option<String> {
+    let session = s.trim();
+    let session = session.strip_prefix("session=").unwrap_or(session).trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Talks to adventofcode.com over HTTP.
+pub struct NativeClient {
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl NativeClient {
+    pub fn new(session: String) -> Self {
+        Self {
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self, Error> {
+        let session = get_session().ok_or_else(|| {
+            Error::Config(
+                "no session cookie found in AOC_SESSION or \"~/.adventofcode.session\".".into(),
+            )
+        })?;
+        Ok(Self::new(session))
+    }
+
+    fn url(puzzle: Puzzle, path: &str) -> String {
+        format!(
+            "{BASE_URL}/{}/day/{}{path}",
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| http_error(url, e))?;
+        Ok(response.into_string()?)
+    }
+}
+
+fn http_error(url: &str, e: ureq::Error) -> Error {
+    match e {
+        ureq::Error::Status(400, _) => Error::Http(format!(
+            "request to {url} was rejected, the session cookie might have expired."
+        )),
+        ureq::Error::Status(404, _) => Error::Http(format!(
+            "{url} was not found, the puzzle might not be unlocked yet."
+        )),
+        ureq::Error::Status(code, _) => Error::Http(format!("{url} returned status {code}.")),
+        ureq::Error::Transport(t) => Error::Http(format!("request to {url} failed: {t}")),
+    }
+}
+
+impl AocClient for NativeClient {
+    fn name(&self) -> &'static str {
+        "native"
+    }
+
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, Error> {
+        self.get(&Self::url(puzzle, "/input"))
+    }
+
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, Error> {
+        let page = self.get(&Self::url(puzzle, ""))?;
+        let articles = extract_elements(&page, "article");
+
+        if articles.is_empty() {
+            return Err(Error::Http(
+                "puzzle page did not contain a description.".into(),
+            ));
+        }
+
+        Ok(puzzle::html_to_markdown(&articles.concat()))
+    }
+
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, Error> {
+        let url = Self::url(puzzle, "/answer");
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| http_error(&url, e))?;
+
+        let page = response.into_string()?;
+        let text = extract_elements(&page, "article")
+            .first()
+            .map_or_else(|| strip_tags(&page), |article| strip_tags(article));
+
+        Ok(text.trim().to_string())
+    }
+}
+
+/// Returns every `<tag ...>...</tag>` element of a page, including the enclosing tags.
+/// Elements of the same tag are not expected to nest, which holds for puzzle pages.
+fn extract_elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut elements = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let Some(len) = rest[start..].find(&close) else {
+            break;
+        };
+        let end = start + len + close.len();
+        elements.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    elements
+}
+
+/// Removes all tags from an HTML fragment and decodes the common entities.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    puzzle::decode_entities(&text)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Serves a local directory that mirrors the website, laid out like `data`:
+/// `YYYY/inputs/DD.txt`, `YYYY/puzzles/DD.md` and `YYYY/answers/DD.txt`.
+/// Submissions are judged against the answers file like the website would.
+pub struct FakeClient {
+    root: PathBuf,
+}
+
+impl FakeClient {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Uses `AOC_FAKE_DIR`, defaulting to `data/fake`.
+    pub fn from_env() -> Self {
+        Self::new(env::var_os("AOC_FAKE_DIR").unwrap_or_else(|| "data/fake".into()))
+    }
+
+    fn read(&self, folder: &str, ext: &str, puzzle: Puzzle) -> Result<String, Error> {
+        let path = self
+            .root
+            .join(puzzle.year.to_string())
+            .join(folder)
+            .join(format!("{}.{ext}", puzzle.day));
+        fs::read_to_string(&path).map_err(|e| {
+            Error::Http(format!(
+                "fake client could not read \"{}\": {e}",
+                path.display()
+            ))
+        })
+    }
+}
+
+impl AocClient for FakeClient {
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, Error> {
+        self.read("inputs", "txt", puzzle)
+    }
+
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, Error> {
+        self.read("puzzles", "md", puzzle)
+    }
+
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, Error> {
+        let answers: Answers = self
+            .read("answers", "txt", puzzle)?
+            .parse()
+            .map_err(|e| Error::Http(format!("fake client could not parse answers: {e}")))?;
+
+        let expected = answers.get(part).ok_or_else(|| {
+            Error::Http(format!(
+                "fake client has no answer for part {part} of {puzzle}."
+            ))
+        })?;
+
+        let response = if expected == answer.trim() {
+            "That's the right answer! You are one gold star closer to saving Christmas."
+        } else {
+            match (expected.parse::<i64>(), answer.trim().parse::<i64>()) {
+                (Ok(e), Ok(a)) if a > e => "That's not the right answer; your answer is too high.",
+                (Ok(e), Ok(a)) if a < e => "That's not the right answer; your answer is too low.",
+                _ => "That's not the right answer.",
+            }
+        };
+
+        Ok(response.to_string())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::fs;
+
+    use super::{extract_elements, parse_session, strip_tags, AocClient, FakeClient};
+    use crate::template::submissions::{parse_response, Outcome};
+    use crate::{day, year, Puzzle};
+
+    #[test]
+    fn parses_session() {
+        assert_eq!(parse_session(" 53616c74\n"), Some("53616c74".into()));
+        assert_eq!(parse_session("session=53616c74"), Some("53616c74".into()));
+        assert_eq!(parse_session("\n"), None);
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let page = "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2></article>\
+            <p>Answer</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2></article></main>";
+        assert_eq!(
+            extract_elements(page, "article"),
+            vec![
+                "<article class=\"day-desc\"><h2>--- Day 1 ---</h2></article>",
+                "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2></article>"
+            ]
+        );
+        assert!(extract_elements("<main></main>", "article").is_empty());
+    }
+
+    #[test]
+    fn strips_tags() {
+        assert_eq!(
+            strip_tags("<article><p>That&#39;s not the right answer; <em>too low</em> &amp; more.</p></article>"),
+            "That's not the right answer; too low & more."
+        );
+    }
+
+    #[test]
+    fn fake_client_judges_submissions() {
+        let root = std::env::temp_dir().join(format!("aoc-fake-{}", std::process::id()));
+        let year_dir = root.join("2023");
+        fs::create_dir_all(year_dir.join("answers")).unwrap();
+        fs::create_dir_all(year_dir.join("inputs")).unwrap();
+        fs::write(year_dir.join("answers").join("05.txt"), "part_one: 35\n").unwrap();
+        fs::write(
+            year_dir.join("inputs").join("05.txt"),
+            "seeds: 79 14 55 13\n",
+        )
+        .unwrap();
+
+        let client = FakeClient::new(&root);
+        let puzzle = Puzzle::new(year!(2023), day!(5));
+        let outcome =
+            |part, answer| parse_response(&client.submit(puzzle, part, answer).unwrap()).outcome;
+
+        assert_eq!(client.fetch_input(puzzle).unwrap(), "seeds: 79 14 55 13\n");
+        assert!(client
+            .fetch_input(Puzzle::new(year!(2022), day!(5)))
+            .is_err());
+        assert_eq!(outcome(1, "35"), Outcome::Correct);
+        assert_eq!(outcome(1, "36"), Outcome::TooHigh);
+        assert_eq!(outcome(1, "34"), Outcome::TooLow);
+        assert_eq!(outcome(1, "abc"), Outcome::Incorrect);
+        assert!(client.submit(puzzle, 2, "1").is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
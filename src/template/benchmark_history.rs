@@ -0,0 +1,331 @@
+/// Module that keeps an append-only history of benchmark timings and compares new runs against it.
+/// Each line of the history file is a tab-separated record of `timestamp, revision, day, part, median, stddev`.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    time::Duration,
+};
+
+use colored::Colorize;
+
+use crate::template::get_data_dir;
+use crate::template::readme_benchmarks::{PartTiming, Timings};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// A single recorded timing of one part of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub revision: String,
+    pub day: Day,
+    pub part: u8,
+    pub timing: PartTiming,
+}
+
+impl Entry {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.revision,
+            self.day,
+            self.part,
+            self.timing.median.as_nanos(),
+            self.timing.stddev.as_nanos()
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, Error> {
+        let err = || Error::Parser(format!("Malformed benchmark history line: {line}"));
+        let fields: Vec<&str> = line.split('\t').collect();
+
+        let [timestamp, revision, day, part, median, stddev] = fields[..] else {
+            return Err(err());
+        };
+
+        let nanos = |s: &str| {
+            s.parse::<u64>()
+                .map(Duration::from_nanos)
+                .map_err(|_| err())
+        };
+
+        Ok(Self {
+            timestamp: timestamp.parse().map_err(|_| err())?,
+            revision: revision.to_string(),
+            day: day.parse().map_err(|_| err())?,
+            part: part.parse().map_err(|_| err())?,
+            timing: PartTiming {
+                median: nanos(median)?,
+                stddev: nanos(stddev)?,
+            },
+        })
+    }
+}
+
+/// The change of one part's median timing relative to the baseline.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Entry,
+    pub current: PartTiming,
+}
+
+impl Comparison {
+    /// Relative change of the median in percent. Positive values mean the solution got slower.
+    #[must_use]
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.timing.median.as_secs_f64();
+        let current = self.current.median.as_secs_f64();
+
+        if baseline == 0.0 {
+            return 0.0;
+        }
+
+        (current - baseline) / baseline * 100.0
+    }
+
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+
+    #[must_use]
+    pub fn is_improvement(&self, threshold: f64) -> bool {
+        self.change() < -threshold
+    }
+}
+
+fn parse_history(s: &str) -> Result<Vec<Entry>, Error> {
+    s.lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(Entry::from_line)
+        .collect()
+}
+
+/// The history file of a year, `data/YYYY/benchmarks.tsv`.
+#[must_use]
+pub fn get_path(year: Year) -> PathBuf {
+    get_data_dir(year).join("benchmarks.tsv")
+}
+
+/// Reads all entries from the history file of a year. A missing file is treated as an empty history.
+pub fn load(year: Year) -> Result<Vec<Entry>, Error> {
+    let path = get_path(year);
+
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    parse_history(&fs::read_to_string(path)?)
+}
+
+/// Appends the timings of the current run to the history file of a year.
+pub fn append(year: Year, entries: &[Entry]) -> Result<(), Error> {
+    let path = get_path(year);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_line())?;
+    }
+
+    Ok(())
+}
+
+/// Converts the timings of a run into history entries.
+#[must_use]
+pub fn to_entries(timings: &[Timings], revision: &str, timestamp: u64) -> Vec<Entry> {
+    timings
+        .iter()
+        .flat_map(|t| {
+            [(1, t.part_1), (2, t.part_2)]
+                .into_iter()
+                .filter_map(move |(part, timing)| {
+                    Some(Entry {
+                        timestamp,
+                        revision: revision.to_string(),
+                        day: t.day,
+                        part,
+                        timing: timing?,
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Compares the timings of a run against the most recent history entry of every part.
+#[must_use]
+pub fn compare(timings: &[Timings], history: &[Entry]) -> Vec<Comparison> {
+    let mut baselines: HashMap<(Day, u8), &Entry> = HashMap::new();
+
+    for entry in history {
+        // later entries win, the history file is append-only.
+        baselines.insert((entry.day, entry.part), entry);
+    }
+
+    to_entries(timings, "", 0)
+        .into_iter()
+        .filter_map(|current| {
+            let baseline = baselines.get(&(current.day, current.part))?;
+            Some(Comparison {
+                day: current.day,
+                part: current.part,
+                baseline: (*baseline).clone(),
+                current: current.timing,
+            })
+        })
+        .collect()
+}
+
+/// Prints a comparison table to `out` and returns the number of regressions beyond `threshold` percent.
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64, out: &mut dyn Write) -> usize {
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let line = format!(
+            "Day {} Part {}: {:.1?} → {:.1?} ({:+.1}%, baseline {})",
+            comparison.day,
+            comparison.part,
+            comparison.baseline.timing.median,
+            comparison.current.median,
+            comparison.change(),
+            comparison.baseline.revision,
+        );
+
+        let line = if comparison.is_regression(threshold) {
+            regressions += 1;
+            line.red().to_string()
+        } else if comparison.is_improvement(threshold) {
+            line.green().to_string()
+        } else {
+            line
+        };
+        let _ = writeln!(out, "{line}");
+    }
+
+    regressions
+}
+
+/// The current git revision, suffixed with `-dirty` if the working tree has uncommitted changes.
+#[must_use]
+pub fn get_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(rev) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{rev}-dirty")
+        }
+        Some(rev) => rev,
+        None => "unknown".into(),
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, parse_history, to_entries, Entry};
+    use crate::day;
+    use crate::template::readme_benchmarks::{PartTiming, Timings};
+
+    fn ms(median: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            median: Duration::from_millis(median),
+            stddev: Duration::from_millis(1),
+        })
+    }
+
+    fn get_mock_timings() -> Vec<Timings> {
+        vec![
+            Timings {
+                day: day!(1),
+                part_1: ms(10),
+                part_2: ms(20),
+                total_nanos: 3e+7,
+            },
+            Timings {
+                day: day!(2),
+                part_1: ms(30),
+                part_2: None,
+                total_nanos: 3e+7,
+            },
+        ]
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entries = to_entries(&get_mock_timings(), "abc1234", 1701388800);
+        let s = entries
+            .iter()
+            .map(Entry::to_line)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            s.lines().next().unwrap(),
+            "1701388800\tabc1234\t01\t1\t10000000\t1000000"
+        );
+        assert_eq!(parse_history(&s).unwrap(), entries);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_malformed_history() {
+        parse_history("1701388800\tabc1234\t01").unwrap();
+    }
+
+    #[test]
+    fn compares_against_latest_entry() {
+        let history = parse_history(
+            [
+                "# timestamp\trevision\tday\tpart\tmedian\tstddev",
+                "1\told\t01\t1\t5000000\t0",
+                "2\tnew\t01\t1\t8000000\t0",
+                "2\tnew\t02\t1\t30000000\t0",
+            ]
+            .join("\n")
+            .as_str(),
+        )
+        .unwrap();
+
+        let comparisons = compare(&get_mock_timings(), &history);
+        assert_eq!(comparisons.len(), 2);
+
+        assert_eq!(comparisons[0].baseline.revision, "new");
+        assert!((comparisons[0].change() - 25.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(30.0));
+
+        assert!(comparisons[1].change().abs() < 1e-9);
+        assert!(!comparisons[1].is_regression(10.0));
+        assert!(!comparisons[1].is_improvement(10.0));
+    }
+}
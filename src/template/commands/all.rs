@@ -1,254 +1,330 @@
-use std::io;
+use std::{collections::BTreeSet, io, process, time::Duration};
+
+use rayon::prelude::*;
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    benchmark_history, get_timestamp,
+    readme_benchmarks::{self, PartTiming, Timings},
+    record::{Format, PartRecord},
+    runner::{Options, Runner, Solution},
+    try_read_file, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
-
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
-        }
+use crate::{all_days, Day, Puzzle, Year};
+
+/// Runs every solution of `year`, or of every year that has solutions if `year` is `None`.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[Solution],
+    year: Option<Year>,
+    is_timed: bool,
+    format: Format,
+    compare_threshold: Option<f64>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) {
+    let is_human = format == Format::Human;
+    // only timings of optimized builds are comparable, debug builds don't update the readme or the history.
+    let is_release = !cfg!(debug_assertions);
+
+    let years = match year {
+        Some(year) => vec![year],
+        None => get_years(solutions),
+    };
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    let options = Options {
+        time: is_timed,
+        format,
+        submit: None,
+        force: false,
+        timeout,
+        ..Options::default()
+    };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut regressions = 0;
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+    for (i, &year) in years.iter().enumerate() {
+        if years.len() > 1 && is_human {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Year {year}{ANSI_RESET}");
+            println!("======");
         }
-    });
 
-    if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
-
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        let timings = run_year(solutions, year, options, jobs);
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
-                }
-            }
+        if is_timed {
+            // the readme holds a single table, only single-year runs update it.
+            let update_readme = is_release && years.len() == 1;
+            regressions += finish_timed(
+                year,
+                &timings,
+                is_release,
+                update_readme,
+                compare_threshold,
+                format,
+            );
         }
     }
-}
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
-}
-
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+    if let (Some(threshold), 1..) = (compare_threshold, regressions) {
+        eprintln!("{regressions} part(s) regressed by more than {threshold}%.");
+        process::exit(1);
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Every year that has at least one solution, in order.
+fn get_years(solutions: &[Solution]) -> Vec<Year> {
+    solutions
+        .iter()
+        .map(|s| s.puzzle.year)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+/// Runs every day of a year and returns the timings of the solved days.
+fn run_year(solutions: &[Solution], year: Year, options: Options, jobs: usize) -> Vec<Timings> {
+    let mut timings: Vec<Timings> = vec![];
+    let is_human = options.format == Format::Human;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    let mut finish_day = |day: Day, records: Vec<PartRecord>| {
+        if records.is_empty() {
+            if is_human {
+                println!("Not solved.");
+            }
+        } else {
+            timings.push(get_timings(day, &records));
         }
+    };
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    // benchmarks run one day at a time so that timings are not skewed by contention.
+    if jobs == 1 || options.time {
+        all_days().for_each(|day| {
+            print_header(day, options.format);
+            let mut runner = Runner::new(options);
+            run_solution(solutions, Puzzle::new(year, day), &mut runner);
+            finish_day(day, runner.into_records());
+        });
+    } else {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .unwrap();
+
+        let runners: Vec<(Day, Runner)> = pool.install(|| {
+            all_days()
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .map(|day| {
+                    let mut runner = Runner::buffered(options);
+                    run_solution(solutions, Puzzle::new(year, day), &mut runner);
+                    (day, runner)
+                })
+                .collect()
+        });
 
-        if is_release {
-            args.push("--release");
+        // parallel iterators preserve order on `collect`, output is printed in day order.
+        for (day, runner) in runners {
+            print_header(day, options.format);
+            print!("{}", runner.output());
+            finish_day(day, runner.into_records());
         }
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+    timings
+}
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+/// Prints the total of a timed run, compares it with the benchmark history and records it.
+/// Returns the number of regressions. Messages go to stderr if the output is not for humans.
+fn finish_timed(
+    year: Year,
+    timings: &[Timings],
+    is_release: bool,
+    update_readme: bool,
+    compare_threshold: Option<f64>,
+    format: Format,
+) -> usize {
+    let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+
+    if format == Format::Human {
+        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+    }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    let regressions = match compare_threshold {
+        Some(threshold) => compare_with_history(year, timings, threshold, format),
+        None => 0,
+    };
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    if update_readme {
+        match readme_benchmarks::update(year, timings.to_vec(), total_millis) {
+            Ok(()) => report(format, "Successfully updated README with benchmarks."),
+            Err(_) => {
+                eprintln!("Failed to update readme with benchmarks.");
+            }
+        }
+    }
 
-        let mut output = vec![];
+    // keep the previous baseline around if this run regressed.
+    if is_release && regressions == 0 {
+        let entries = benchmark_history::to_entries(
+            timings,
+            &benchmark_history::get_revision(),
+            get_timestamp(),
+        );
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        if let Err(e) = benchmark_history::append(year, &entries) {
+            eprintln!("Failed to record benchmark history: {e:?}");
+        }
+    }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    regressions
+}
+
+/// Compares timings against the benchmark history of a year and returns the number of regressions.
+fn compare_with_history(year: Year, timings: &[Timings], threshold: f64, format: Format) -> usize {
+    let history = match benchmark_history::load(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e:?}");
+            process::exit(1);
         }
+    };
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    let comparisons = benchmark_history::compare(timings, &history);
 
-        Ok(output)
-    }
+    report(
+        format,
+        &format!("\n{ANSI_BOLD}Comparison with last baseline{ANSI_RESET}\n------"),
+    );
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
-
-        timings
+    if comparisons.is_empty() {
+        report(format, "No baseline recorded yet.");
+        return 0;
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    match format {
+        Format::Human => {
+            benchmark_history::print_comparisons(&comparisons, threshold, &mut io::stdout())
+        }
+        Format::Json => {
+            benchmark_history::print_comparisons(&comparisons, threshold, &mut io::stderr())
+        }
     }
+}
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+/// Prints a message to stdout, or to stderr if stdout holds machine-readable records.
+fn report(format: Format, message: &str) {
+    match format {
+        Format::Human => println!("{message}"),
+        Format::Json => eprintln!("{message}"),
     }
+}
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+pub(crate) fn print_header(day: Day, format: Format) {
+    if format == Format::Human {
+        if day > 1 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
     }
+}
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+/// Runs the registered solution for a given puzzle in-process.
+/// Puzzles that have not been scaffolded yet or lack an input file do not run any parts.
+pub(crate) fn run_solution(solutions: &[Solution], puzzle: Puzzle, runner: &mut Runner) {
+    let Some(solution) = solutions.iter().find(|s| s.puzzle == puzzle) else {
+        return;
+    };
+
+    let input = match try_read_file("inputs", puzzle) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not open input file for {puzzle}: {e}");
+            return;
         }
+    };
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+    (solution.solve)(&input, runner);
+}
+
+/// Collects the timings of a day's part records.
+fn get_timings(day: Day, records: &[PartRecord]) -> Timings {
+    let mut timings = Timings {
+        day,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+    };
+
+    for record in records.iter().filter(|r| r.success()) {
+        let timing = Some(PartTiming {
+            median: record.stats.median,
+            stddev: record.stats.stddev,
+        });
+
+        match record.part {
+            1 => timings.part_1 = timing,
+            2 => timings.part_2 = timing,
+            _ => continue,
         }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timings.total_nanos += record.stats.median.as_nanos() as f64;
         }
     }
+
+    timings
+}
+
+/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+#[cfg(all(test, feature = "test_lib"))]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            (*a - *b).abs() < 1.0e-6,
+            "{} is not approximately equal to {}",
+            *a,
+            *b
+        );
+    }};
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::get_timings;
+    use crate::day;
+    use crate::template::record::get_mock_record;
+
+    #[test]
+    fn test_well_formed() {
+        let res = get_timings(
+            day!(1),
+            &[
+                get_mock_record(1, Some("0"), &[70, 74, 78]),
+                get_mock_record(2, Some("10"), &[74_130_000]),
+            ],
+        );
+        assert_approx_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.part_1.unwrap().to_string(), "74.0ns ± 3.0ns");
+        assert_eq!(res.part_2.unwrap().to_string(), "74.1ms");
+    }
+
+    #[test]
+    fn test_missing_parts() {
+        let res = get_timings(
+            day!(1),
+            &[
+                get_mock_record(1, None, &[10]),
+                get_mock_record(2, None, &[10]),
+            ],
+        );
+        assert_approx_eq!(res.total_nanos, 0_f64);
+        assert!(res.part_1.is_none());
+        assert!(res.part_2.is_none());
+    }
 }
@@ -1,15 +1,208 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::answers::Error;
+use crate::template::examples::{self, Expectations};
+use crate::template::puzzle::{self, Suggestion};
+use crate::template::{aoc_client, get_data_path};
+use crate::Puzzle;
+use std::io::{self, IsTerminal, Write};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: Puzzle) {
+    let client = match aoc_client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let puzzle_path = puzzle::get_path(puzzle);
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let result = client.fetch_input(puzzle).and_then(|input| {
+        if let Some(parent) = input_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&input_path, input)?;
+        let description = client.fetch_puzzle(puzzle)?;
+        puzzle::save(puzzle, &description)?;
+        Ok(description)
+    });
+
+    let description = match result {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("failed to download puzzle via {}: {e}", client.name());
+            process::exit(1);
+        }
     };
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+
+    if let Err(e) = save_examples(puzzle, &description) {
+        eprintln!("Failed to write the examples of {puzzle}: {e}");
+    }
+}
+
+/// Writes the examples found in the description and the expected answers that are not known yet.
+/// Ambiguous suggestions are confirmed by the user, or skipped if nobody can be asked.
+fn save_examples(puzzle: Puzzle, description: &str) -> Result<(), Error> {
+    let mut expectations = Expectations::load(puzzle)?;
+    // the name and text of the example of part one, which part two usually reuses.
+    let mut part_one_example: Option<(String, String)> = None;
+    let mut is_changed = false;
+
+    for suggestion in puzzle::suggest_examples(description) {
+        let part = suggestion.part;
+        let (example, answer) = if suggestion.is_ambiguous {
+            confirm(&suggestion)
+        } else {
+            (
+                suggestion.examples.first().cloned(),
+                suggestion.answers.first().cloned(),
+            )
+        };
+
+        let name = match (example, &part_one_example) {
+            (Some(text), Some((name, part_one))) if text.trim_end() == part_one.trim_end() => {
+                name.clone()
+            }
+            (Some(text), _) => {
+                let name = match part {
+                    1 => puzzle.day.to_string(),
+                    _ => format!("{}-{part}", puzzle.day),
+                };
+                if !write_example(puzzle, &name, &text)? {
+                    continue;
+                }
+                if part == 1 {
+                    part_one_example = Some((name.clone(), text));
+                }
+                name
+            }
+            (None, Some((name, _))) if suggestion.examples.is_empty() => name.clone(),
+            (None, _) => continue,
+        };
+
+        // answers that were written before, maybe corrected by hand, are kept.
+        match (answer, expectations.get(&name, part)) {
+            (Some(answer), None) => {
+                expectations.set(&name, part, &answer);
+                is_changed = true;
+                println!("🎄 Expecting {answer} for part {part} of example {name}.");
+            }
+            (Some(answer), Some(existing)) if answer != existing => println!(
+                "Kept the expected answer {existing} for part {part} of example {name}, the description suggests {answer}."
+            ),
+            _ => {}
+        }
+    }
+
+    if is_changed {
+        expectations.save(puzzle)?;
+    }
+    Ok(())
+}
+
+/// Writes an example unless a different one was written before, returns whether the file holds the example.
+fn write_example(puzzle: Puzzle, name: &str, text: &str) -> io::Result<bool> {
+    let path = examples::get_path(puzzle, name);
+
+    match fs::read_to_string(&path) {
+        // examples that were pasted by hand often differ in trailing whitespace.
+        Ok(existing) if existing.trim_end() == text.trim_end() => return Ok(true),
+        Ok(existing) if !existing.trim().is_empty() => {
+            println!("Kept the existing example \"{}\".", path.display());
+            return Ok(false);
+        }
+        _ => {}
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, text)?;
+    println!("🎄 Successfully wrote example to \"{}\".", path.display());
+    Ok(true)
+}
+
+/// Asks which example and answer of an ambiguous suggestion are right.
+fn confirm(suggestion: &Suggestion) -> (Option<String>, Option<String>) {
+    let part = suggestion.part;
+
+    if !io::stdin().is_terminal() {
+        println!("Skipped the ambiguous example of part {part}, run `cargo download` in a terminal to choose one.");
+        return (None, None);
+    }
+
+    println!("\nThe example of part {part} is ambiguous.");
+    let previews: Vec<String> = suggestion
+        .examples
+        .iter()
+        .map(|example| {
+            let lines: Vec<&str> = example.lines().collect();
+            let preview = lines
+                .iter()
+                .take(PREVIEW_LINES)
+                .copied()
+                .collect::<Vec<_>>();
+            // continuation lines are indented below the number of the option.
+            match lines.len() - preview.len() {
+                0 => preview.join("\n    "),
+                more => format!("{}\n    … {more} more line(s)", preview.join("\n    ")),
+            }
+        })
+        .collect();
+
+    let example = choose(&format!("Example of part {part}"), &previews)
+        .map(|i| suggestion.examples[i].clone());
+    let answer = choose(
+        &format!("Expected answer of part {part}"),
+        &suggestion.answers,
+    )
+    .map(|i| suggestion.answers[i].clone());
+    (example, answer)
+}
+
+/// Lines of an example that are shown when choosing one.
+const PREVIEW_LINES: usize = 5;
+
+/// Lets the user pick one of `options`, the first one by default. `None` if there are no options
+/// or the user skipped.
+fn choose(prompt: &str, options: &[String]) -> Option<usize> {
+    match options {
+        [] => return None,
+        [_] => return Some(0),
+        _ => {}
+    }
+
+    for (i, option) in options.iter().enumerate() {
+        println!("[{}] {option}", i + 1);
+    }
+
+    loop {
+        print!("{prompt} [1-{}, Enter for 1, s to skip]: ", options.len());
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line).is_err() {
+            return None;
+        }
+
+        match line.trim() {
+            "" => return Some(0),
+            "s" => return None,
+            choice => match choice.parse::<usize>() {
+                Ok(n @ 1..) if n <= options.len() => return Some(n - 1),
+                _ => println!("Expecting a number between 1 and {}.", options.len()),
+            },
+        }
+    }
 }
@@ -1,16 +1,46 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::{aoc_client, puzzle};
+use crate::Puzzle;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Prints the description of a puzzle from the cache, fetching it first if it is missing
+/// or if part two was unlocked since it was cached.
+pub fn handle(puzzle: Puzzle) {
+    let cached = puzzle::load(puzzle);
+
+    if let Some(description) = cached.as_ref().filter(|d| !puzzle::is_stale(puzzle, d)) {
+        print!("{description}");
+        return;
+    }
+
+    match fetch(puzzle) {
+        Ok(description) => print!("{description}"),
+        Err(e) => match cached {
+            Some(description) => {
+                eprintln!("Could not fetch part two, showing cached puzzle: {e}");
+                print!("{description}");
+            }
+            None => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        },
+    }
+}
+
+fn fetch(puzzle: Puzzle) -> Result<String, String> {
+    let client = aoc_client::from_env().map_err(|e| e.to_string())?;
+
+    let description = client
+        .fetch_puzzle(puzzle)
+        .map_err(|e| format!("failed to read puzzle via {}: {e}", client.name()))?;
+
+    if let Err(e) = puzzle::save(puzzle, &description) {
+        eprintln!(
+            "Failed to cache puzzle in \"{}\": {e}",
+            puzzle::get_path(puzzle).display()
+        );
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    Ok(description)
 }
@@ -1,12 +1,15 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::examples::get_expectations_path;
+use crate::template::get_data_path;
+use crate::{Puzzle, Year};
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
 pub fn part_one(input: &str) -> Option<u32> {
     None
@@ -15,37 +18,31 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     None
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
 "#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: Puzzle) {
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let example_path = get_data_path("examples", puzzle, "txt");
+    let expectations_path = get_expectations_path(puzzle);
+    let module_path = Path::new("src")
+        .join("bin")
+        .join(format!("{}.rs", puzzle.bin_name()));
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -57,11 +54,12 @@ pub fn handle(day: Day) {
 
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
+            .replace("YEAR_NUMBER", &puzzle.year.to_string())
+            .replace("DAY_NUMBER", &puzzle.day.into_inner().to_string())
             .as_bytes(),
     ) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -71,7 +69,7 @@ pub fn handle(day: Day) {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -81,7 +79,7 @@ pub fn handle(day: Day) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -89,6 +87,27 @@ pub fn handle(day: Day) {
         }
     }
 
+    // the tests of a day are generated from its expectations, see `examples`.
+    match create_file(&expectations_path) {
+        Ok(_) => {
+            println!(
+                "Created empty expectations file \"{}\"",
+                expectations_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to create expectations file: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    if Year::from_env() == Some(puzzle.year) {
+        println!("🎄 Type `cargo solve {}` to run your solution.", puzzle.day);
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            puzzle.day, puzzle.year
+        );
+    }
 }
@@ -1,9 +1,25 @@
 use std::process::{Command, Stdio};
+use std::time::Duration;
 
-use crate::Day;
+use crate::template::record::Format;
+use crate::Puzzle;
+use crate::{trace, viz};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    time: bool,
+    submit_part: Option<u8>,
+    force: bool,
+    format: Format,
+    timeout: Option<Duration>,
+    visualize: Option<viz::Output>,
+    trace: Option<trace::Parts>,
+    trace_log: bool,
+    input: Option<String>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if release {
         cmd_args.push("--release".to_string());
@@ -14,12 +30,43 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if force {
+            cmd_args.push("--force".to_string());
+        }
     }
 
     if time {
         cmd_args.push("--time".to_string());
     }
 
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs_f64().to_string());
+    }
+
+    if let Some(visualize) = visualize {
+        cmd_args.push(format!("--visualize={visualize}"));
+    }
+
+    if let Some(trace) = trace {
+        cmd_args.push(format!("--trace={trace}"));
+    }
+
+    if trace_log {
+        cmd_args.push("--trace-log".to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
+    if format != Format::Human {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
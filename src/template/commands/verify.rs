@@ -0,0 +1,48 @@
+use std::{process, time::Duration};
+
+use crate::template::commands::all::{print_header, run_solution};
+use crate::template::record::Format;
+use crate::template::runner::{Options, Runner, Solution};
+use crate::template::{get_data_path, ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day, Puzzle, Year};
+
+/// Runs every solution of a year that has an input file (or the given day) and compares the answers against `data/YYYY/answers`.
+/// Exits with a non-zero status if any answer does not match.
+pub fn handle(solutions: &[Solution], year: Year, day: Option<Day>, timeout: Option<Duration>) {
+    let mut correct = 0;
+    let mut incorrect = 0;
+    let mut unknown = 0;
+
+    let options = Options {
+        timeout,
+        ..Options::default()
+    };
+
+    all_days()
+        .filter(|d| day.is_none_or(|day| day == *d))
+        .map(|d| Puzzle::new(year, d))
+        .filter(|p| solutions.iter().any(|s| s.puzzle == *p))
+        .filter(|p| get_data_path("inputs", *p, "txt").exists())
+        .for_each(|puzzle| {
+            print_header(puzzle.day, Format::Human);
+
+            let mut runner = Runner::new(options);
+            run_solution(solutions, puzzle, &mut runner);
+
+            for record in runner.into_records() {
+                match record.correct {
+                    Some(true) => correct += 1,
+                    Some(false) => incorrect += 1,
+                    None => unknown += 1,
+                }
+            }
+        });
+
+    println!(
+        "\n{ANSI_BOLD}Verified:{ANSI_RESET} {correct} correct, {incorrect} incorrect, {unknown} without recorded answer."
+    );
+
+    if incorrect > 0 {
+        process::exit(1);
+    }
+}
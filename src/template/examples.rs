@@ -0,0 +1,213 @@
+/// Module that stores the examples of a puzzle and their expected answers.
+///
+/// A day can have several examples: `data/YYYY/examples/DD.txt` and variants like `DD-1.txt`,
+/// `DD-2.txt` or `DD-a.txt`. Their expected answers go in `data/YYYY/expectations/DD.txt`,
+/// one line per example and part:
+/// ```text
+/// 01-1 part_one: 142
+/// 01-2 part_two: 281
+/// ```
+/// `build.rs` generates one test per line, which runs the part against the example.
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::{fs, io};
+
+use crate::template::answers::Error;
+use crate::template::runner::PartResult;
+use crate::template::{get_data_dir, get_data_path};
+use crate::Puzzle;
+
+/// The file of an example, `name` is `DD` or a variant like `DD-1`.
+#[must_use]
+pub fn get_path(puzzle: Puzzle, name: &str) -> PathBuf {
+    get_data_dir(puzzle.year)
+        .join("examples")
+        .join(format!("{name}.txt"))
+}
+
+/// The names of the examples of a puzzle that exist, `DD` first and then its variants in order.
+pub fn names(puzzle: Puzzle) -> io::Result<Vec<String>> {
+    let day = puzzle.day.to_string();
+    let dir = get_data_dir(puzzle.year).join("examples");
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut names = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+
+        if name == day || name.strip_prefix(&day).is_some_and(|v| v.starts_with('-')) {
+            names.push(name.to_string());
+        }
+    }
+
+    // `DD` sorts before `DD-…`.
+    names.sort();
+    Ok(names)
+}
+
+/// The expected answer of an example for a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expectation {
+    pub example: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// The expected answers of the examples of a day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expectations {
+    pub entries: Vec<Expectation>,
+}
+
+#[must_use]
+pub fn get_expectations_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path("expectations", puzzle, "txt")
+}
+
+impl Expectations {
+    /// Reads the expectations of a puzzle. A missing file is treated as no expectations.
+    pub fn load(puzzle: Puzzle) -> Result<Self, Error> {
+        let path = get_expectations_path(puzzle);
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Writes the expectations of a puzzle, creating `data/YYYY/expectations` if needed.
+    pub fn save(&self, puzzle: Puzzle) -> Result<(), Error> {
+        let path = get_expectations_path(puzzle);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, example: &str, part: u8) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.example == example && e.part == part)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Sets the expected answer of an example, replacing a previous one.
+    pub fn set(&mut self, example: &str, part: u8, answer: &str) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.example == example && e.part == part)
+        {
+            Some(entry) => entry.answer = answer.into(),
+            None => self.entries.push(Expectation {
+                example: example.into(),
+                part,
+                answer: answer.into(),
+            }),
+        }
+    }
+}
+
+impl FromStr for Expectations {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expectations = Self::default();
+
+        for line in s.lines().filter(|l| !l.trim().is_empty()) {
+            let malformed = || Error::Parser(format!("Malformed expectation line: {line}"));
+
+            let (key, answer) = line.split_once(':').ok_or_else(malformed)?;
+            let (example, part) = key.trim().split_once(' ').ok_or_else(malformed)?;
+            let part = match part.trim() {
+                "part_one" => 1,
+                "part_two" => 2,
+                key => return Err(Error::Parser(format!("Unknown expectation key: {key}"))),
+            };
+
+            expectations.set(example, part, answer.trim());
+        }
+
+        Ok(expectations)
+    }
+}
+
+impl Display for Expectations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in &self.entries {
+            let part = if entry.part == 1 {
+                "part_one"
+            } else {
+                "part_two"
+            };
+            writeln!(f, "{} {part}: {}", entry.example, entry.answer)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Runs a part against an example and compares its answer with the expected one,
+/// used by the tests that `build.rs` generates.
+///
+/// # Panics
+/// Panics if the example or its expectation can't be read, or if the answer is not the expected one.
+pub fn check<R: PartResult>(puzzle: Puzzle, example: &str, part: u8, func: impl Fn(&str) -> R) {
+    let expectations = Expectations::load(puzzle)
+        .unwrap_or_else(|e| panic!("could not read the expectations of {puzzle}: {e}"));
+    let expected = expectations
+        .get(example, part)
+        .unwrap_or_else(|| panic!("no expected answer for part {part} of example {example}"));
+    let input = fs::read_to_string(get_path(puzzle, example))
+        .unwrap_or_else(|e| panic!("could not read example {example}: {e}"));
+
+    match func(&input).into_answer() {
+        Ok(Some(answer)) => assert_eq!(
+            answer.to_string().trim(),
+            expected,
+            "wrong answer for part {part} of example {example}"
+        ),
+        Ok(None) => panic!("part {part} is not implemented"),
+        Err(e) => panic!("part {part} failed on example {example}: {e}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Expectations;
+
+    #[test]
+    fn parses_expectations() {
+        let expectations: Expectations =
+            "01-1 part_one: 142\n\n01-2 part_two:  281 \n01-1 part_one: 143"
+                .parse()
+                .unwrap();
+        assert_eq!(expectations.get("01-1", 1), Some("143"));
+        assert_eq!(expectations.get("01-2", 2), Some("281"));
+        assert_eq!(expectations.get("01-2", 1), None);
+        assert_eq!(
+            expectations.to_string(),
+            "01-1 part_one: 143\n01-2 part_two: 281\n"
+        );
+
+        assert!("01 part_three: 1".parse::<Expectations>().is_err());
+        assert!("part_one: 1".parse::<Expectations>().is_err());
+    }
+}
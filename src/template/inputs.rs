@@ -0,0 +1,273 @@
+/// Selects the inputs that a solution binary runs against: the account's input and every input in
+/// `data/YYYY/inputs/DD/`, a file or directory given with `--input <path>`, or stdin with `--input -`.
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use crate::template::record::{Format, PartRecord, Status};
+use crate::template::runner::{Options, Runner};
+use crate::template::{get_data_dir, get_data_path, ANSI_BOLD, ANSI_RESET};
+use crate::Puzzle;
+
+/// A named puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    /// Whether it's the account's input in `data/YYYY/inputs/DD.txt`, whose answers are verified and submitted.
+    pub is_own: bool,
+}
+
+/// Where the inputs come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `data/YYYY/inputs/DD.txt` followed by the inputs in [`get_dir`], e.g. those of teammates.
+    Default,
+    /// A file, or every `*.txt` file in a directory.
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Reads the source from the `--input` argument of a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        match args
+            .iter()
+            .position(|x| x == "--input")
+            .map(|i| args.get(i + 1))
+        {
+            None => Source::Default,
+            Some(Some(value)) if value == "-" => Source::Stdin,
+            Some(Some(value)) => Source::Path(PathBuf::from(value)),
+            Some(None) => {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --input <path>");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Reads the inputs of `puzzle`, in order of their names.
+    pub fn load(&self, puzzle: Puzzle) -> io::Result<Vec<Input>> {
+        match self {
+            Source::Default => {
+                let path = get_data_path("inputs", puzzle, "txt");
+                let dir = get_dir(puzzle);
+                let mut inputs = if dir.is_dir() {
+                    read_dir(&dir)?
+                } else {
+                    vec![]
+                };
+
+                // without any input, the error names the file of the account's input.
+                if path.exists() || inputs.is_empty() {
+                    inputs.insert(
+                        0,
+                        Input {
+                            name: puzzle.day.to_string(),
+                            text: fs::read_to_string(path)?,
+                            is_own: true,
+                        },
+                    );
+                }
+
+                Ok(inputs)
+            }
+            Source::Path(path) if path.is_dir() => match read_dir(path)? {
+                inputs if inputs.is_empty() => Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no *.txt inputs in {}", path.display()),
+                )),
+                inputs => Ok(inputs),
+            },
+            Source::Path(path) => Ok(vec![Input {
+                name: get_name(path),
+                text: fs::read_to_string(path)?,
+                is_own: false,
+            }]),
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(vec![Input {
+                    name: "stdin".into(),
+                    text,
+                    is_own: false,
+                }])
+            }
+        }
+    }
+}
+
+/// The directory of several inputs of a day, e.g. those of teammates: `data/YYYY/inputs/DD/`.
+#[must_use]
+pub fn get_dir(puzzle: Puzzle) -> PathBuf {
+    get_data_dir(puzzle.year)
+        .join("inputs")
+        .join(puzzle.day.to_string())
+}
+
+fn get_name(path: &Path) -> String {
+    path.file_stem().map_or_else(
+        || path.display().to_string(),
+        |s| s.to_string_lossy().into(),
+    )
+}
+
+/// Reads every `*.txt` file in `dir`.
+fn read_dir(dir: &Path) -> io::Result<Vec<Input>> {
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            Ok(Input {
+                name: get_name(path),
+                text: fs::read_to_string(path)?,
+                is_own: false,
+            })
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Runs `run_parts` against the inputs selected by the command line, used by the `main` function
+/// of the `solution!` macro. Several inputs are followed by a table of their answers.
+pub fn run(puzzle: Puzzle, run_parts: fn(&str, &mut Runner)) {
+    let options = Options::from_args();
+    let inputs = match Source::from_args().load(puzzle) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to read the input of {puzzle}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let is_human = options.format == Format::Human;
+    let is_table = inputs.len() > 1;
+    let mut rows = vec![];
+
+    for (i, input) in inputs.into_iter().enumerate() {
+        if is_table && is_human {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}{}{ANSI_RESET}", input.name);
+        }
+
+        let mut runner = Runner::new(Options {
+            custom_input: !input.is_own,
+            ..options
+        });
+        runner.set_input(&input.name);
+        run_parts(&input.text, &mut runner);
+        rows.push((input.name, runner.into_records()));
+    }
+
+    if is_table && is_human {
+        println!("\n{}", format_table(&rows));
+    }
+}
+
+/// A table with the answers of both parts for every input.
+fn format_table(rows: &[(String, Vec<PartRecord>)]) -> String {
+    let cells: Vec<[String; 3]> = rows
+        .iter()
+        .map(|(name, records)| {
+            let answer = |part: u8| {
+                records
+                    .iter()
+                    .find(|record| record.part == part)
+                    .map_or_else(|| "-".into(), format_answer)
+            };
+            [name.clone(), answer(1), answer(2)]
+        })
+        .collect();
+
+    let header = ["Input".to_string(), "Part 1".into(), "Part 2".into()];
+    let widths: [usize; 3] = std::array::from_fn(|i| {
+        cells
+            .iter()
+            .chain([&header])
+            .map(|row| row[i].chars().count())
+            .max()
+            .unwrap_or(0)
+    });
+
+    let format_row = |row: &[String; 3]| {
+        let padded: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        format!("| {} |", padded.join(" | "))
+    };
+    let separator = widths.map(|width| "-".repeat(width));
+
+    [header, separator]
+        .iter()
+        .chain(&cells)
+        .map(format_row)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_answer(record: &PartRecord) -> String {
+    match (&record.answer, record.status) {
+        (Some(answer), _) if answer.contains('\n') => "▼".into(),
+        (Some(answer), _) => answer.clone(),
+        (None, Status::NotImplemented) => "-".into(),
+        (None, status) => format!("✖ {status}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::format_table;
+    use crate::template::record::{get_mock_record, PartRecord, Status};
+
+    #[test]
+    fn formats_answer_tables() {
+        let rows = vec![
+            (
+                "alice".to_string(),
+                vec![
+                    get_mock_record(1, Some("35"), &[1]),
+                    get_mock_record(2, Some("46"), &[1]),
+                ],
+            ),
+            (
+                "bob".to_string(),
+                vec![
+                    get_mock_record(1, Some("123456"), &[1]),
+                    PartRecord {
+                        status: Status::Panicked,
+                        ..get_mock_record(2, None, &[1])
+                    },
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            format_table(&rows),
+            [
+                "| Input | Part 1 | Part 2     |",
+                "| ----- | ------ | ---------- |",
+                "| alice | 35     | 46         |",
+                "| bob   | 123456 | ✖ panicked |",
+            ]
+            .join("\n")
+        );
+    }
+}
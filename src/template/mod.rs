@@ -1,36 +1,101 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Puzzle, Year};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod examples;
+pub mod inputs;
+pub mod puzzle;
 pub mod readme_benchmarks;
+pub mod record;
 pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// The directory that holds the data files of a year, `data/YYYY`.
+#[must_use]
+pub fn get_data_dir(year: Year) -> PathBuf {
+    Path::new("data").join(year.to_string())
+}
+
+/// Path of a puzzle's file in one of the data folders, e.g. `data/2023/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: Puzzle, extension: &str) -> PathBuf {
+    get_data_dir(puzzle.year)
+        .join(folder)
+        .join(format!("{}.{extension}", puzzle.day))
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
+    try_read_file(folder, puzzle).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it does not exist.
+pub fn try_read_file(folder: &str, puzzle: Puzzle) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    fs::read_to_string(cwd.join(get_data_path(folder, puzzle, "txt")))
+}
+
+/// The current unix timestamp in seconds.
+#[must_use]
+pub fn get_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE`, a `run_parts` function that runs both parts
+/// and a `main` function that runs them against the inputs of the command line, see [`inputs`].
+/// Tests of the examples with expected answers are generated as well, see [`examples`].
+/// `solution!(2023, 5, timeout = 60)` gives the parts of a day 60 seconds, whatever the `--timeout`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@impl $year, $day, None);
+    };
+    ($year:expr, $day:expr, timeout = $secs:expr) => {
+        advent_of_code::solution!(@impl $year, $day, Some(std::time::Duration::from_secs($secs)));
+    };
+    (@impl $year:expr, $day:expr, $timeout:expr) => {
+        /// The current year.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::Puzzle = advent_of_code::Puzzle::new(YEAR, DAY);
+        /// Overrides the `--timeout` of the command line for this day.
+        const TIMEOUT: Option<std::time::Duration> = $timeout;
+
+        /// Runs both parts of the solution against `input`.
+        #[allow(dead_code)]
+        pub fn run_parts(input: &str, runner: &mut advent_of_code::template::runner::Runner) {
+            if let Some(timeout) = TIMEOUT {
+                runner.override_timeout(timeout);
+            }
 
+            runner.run_part(part_one, input, PUZZLE, 1);
+            runner.run_part(part_two, input, PUZZLE, 2);
+        }
+
+        #[allow(dead_code)]
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            advent_of_code::template::inputs::run(PUZZLE, run_parts);
+        }
+
+        /// One test per example and part in `data/YYYY/expectations/DD.txt`, generated by `build.rs`.
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(env!("OUT_DIR"), "/examples/", env!("CARGO_BIN_NAME"), ".rs"));
         }
     };
 }
@@ -0,0 +1,418 @@
+/// Module that renders puzzle descriptions to Markdown and caches them in `data/YYYY/puzzles/DD.md`.
+use std::{cmp, fs, io, path::PathBuf};
+
+use crate::template::{answers::Answers, get_data_path};
+use crate::Puzzle;
+
+/// Heading that the website puts above the second part of a puzzle.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+#[must_use]
+pub fn get_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path("puzzles", puzzle, "md")
+}
+
+/// Reads the cached description of a puzzle, `None` if it was never fetched.
+#[must_use]
+pub fn load(puzzle: Puzzle) -> Option<String> {
+    fs::read_to_string(get_path(puzzle)).ok()
+}
+
+/// Writes the description of a puzzle to the cache, creating `data/YYYY/puzzles` if needed.
+pub fn save(puzzle: Puzzle, description: &str) -> io::Result<()> {
+    let path = get_path(puzzle);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, description)
+}
+
+#[must_use]
+pub fn has_part_two(description: &str) -> bool {
+    description.contains(PART_TWO_HEADING)
+}
+
+/// Whether a cached description should be fetched again because part two was unlocked since.
+#[must_use]
+pub fn is_stale(puzzle: Puzzle, description: &str) -> bool {
+    !has_part_two(description) && Answers::load(puzzle).is_ok_and(|a| a.part_one.is_some())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Descriptions introduce their examples with phrases like "For example" or "Here is an example".
+const EXAMPLE_PHRASE: &str = "example";
+
+/// The example of a part, as found in its description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub part: u8,
+    /// Candidate examples, the most likely first. Empty if the part has no example of its own,
+    /// part two usually reuses the example of part one.
+    pub examples: Vec<String>,
+    /// Candidate answers of the example, the most likely first.
+    pub answers: Vec<String>,
+    /// Whether several examples or answers are equally likely, and should be confirmed.
+    pub is_ambiguous: bool,
+}
+
+/// Finds the examples of the parts in a Markdown description: the largest multi-line code block
+/// that follows a phrase like "For example", and the last emphasized code after it as its answer.
+#[must_use]
+pub fn suggest_examples(description: &str) -> Vec<Suggestion> {
+    let (part_one, part_two) = match description.split_once(PART_TWO_HEADING) {
+        Some((part_one, part_two)) => (part_one, Some(part_two)),
+        None => (description, None),
+    };
+
+    [Some(part_one), part_two]
+        .into_iter()
+        .flatten()
+        .zip(1..)
+        .map(|(section, part)| suggest_example(section, part))
+        .collect()
+}
+
+enum Block<'a> {
+    Text(String),
+    Code(Vec<&'a str>),
+}
+
+/// Splits Markdown into paragraphs and code blocks.
+fn get_blocks(markdown: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut code: Option<Vec<&str>> = None;
+    let mut text = String::new();
+
+    for line in markdown.lines() {
+        match (&mut code, line.trim_end()) {
+            (None, "```") => {
+                if !text.is_empty() {
+                    blocks.push(Block::Text(std::mem::take(&mut text)));
+                }
+                code = Some(vec![]);
+            }
+            (Some(_), "```") => blocks.push(Block::Code(code.take().unwrap_or_default())),
+            (Some(lines), _) => lines.push(line),
+            (None, "") => {
+                if !text.is_empty() {
+                    blocks.push(Block::Text(std::mem::take(&mut text)));
+                }
+            }
+            (None, line) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        blocks.push(Block::Text(text));
+    }
+    blocks
+}
+
+fn suggest_example(section: &str, part: u8) -> Suggestion {
+    let blocks = get_blocks(section);
+
+    // the index, lines and whether the block is introduced as an example, for every multi-line block.
+    let mut candidates: Vec<(usize, &[&str], bool)> = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, block)| match block {
+            Block::Code(lines) if lines.len() > 1 => {
+                let is_introduced = i.checked_sub(1).is_some_and(|previous| {
+                    matches!(&blocks[previous], Block::Text(text) if text.to_lowercase().contains(EXAMPLE_PHRASE))
+                });
+                Some((i, lines.as_slice(), is_introduced))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let introduced = candidates
+        .iter()
+        .filter(|(_, _, is_introduced)| *is_introduced)
+        .count();
+    candidates.sort_by_key(|(_, lines, is_introduced)| {
+        (cmp::Reverse(*is_introduced), cmp::Reverse(lines.len()))
+    });
+
+    // the answer follows the example, later values are usually closer to the final answer.
+    let start = candidates.first().map_or(0, |(i, _, _)| i + 1);
+    let mut answers: Vec<String> = vec![];
+    for block in &blocks[start..] {
+        if let Block::Text(text) = block {
+            for answer in get_emphasized_code(text) {
+                answers.retain(|a| *a != answer);
+                answers.push(answer);
+            }
+        }
+    }
+    answers.reverse();
+
+    Suggestion {
+        part,
+        is_ambiguous: (candidates.len() > 1 && introduced != 1) || answers.len() > 1,
+        examples: candidates
+            .into_iter()
+            .map(|(_, lines, _)| lines.join("\n") + "\n")
+            .collect(),
+        answers,
+    }
+}
+
+/// The emphasized inline code of a paragraph, `*`142`*` in Markdown.
+fn get_emphasized_code(text: &str) -> Vec<String> {
+    let mut found = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find("*`") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("`*") else {
+            break;
+        };
+        found.push(after[..end].to_string());
+        rest = &after[end + 2..];
+    }
+
+    found
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Converts the `<article>` elements of a puzzle page to Markdown.
+/// Supports the subset of HTML the website uses: headings, paragraphs, lists, links,
+/// code blocks, inline code and emphasis. Unknown tags are dropped, their text is kept.
+#[must_use]
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    let mut in_pre = false;
+    // start offset of the open inline `<code>` and whether it contained emphasis.
+    let mut code: Option<(usize, bool)> = None;
+    // start offset and target of every open link.
+    let mut links: Vec<(usize, String)> = vec![];
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..start], in_pre);
+
+        let Some(len) = rest[start..].find('>') else {
+            push_text(&mut out, &rest[start..], in_pre);
+            break;
+        };
+
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => {
+                end_block(&mut out);
+                out.push_str("## ");
+            }
+            ("h2" | "p" | "ul" | "article", true) => end_block(&mut out),
+            ("li", false) => {
+                end_line(&mut out);
+                out.push_str("- ");
+            }
+            ("li" | "br", _) => end_line(&mut out),
+            ("pre", false) => {
+                end_block(&mut out);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                end_line(&mut out);
+                out.push_str("```");
+                end_block(&mut out);
+                in_pre = false;
+            }
+            // code blocks can't contain formatting.
+            (_, _) if in_pre => {}
+            ("code", false) => {
+                code = Some((out.len(), false));
+                out.push('`');
+            }
+            ("code", true) => {
+                out.push('`');
+                // emphasis can't be nested in inline code, so it wraps the code instead.
+                if let Some((start, true)) = code {
+                    out.insert(start, '*');
+                    out.push('*');
+                }
+                code = None;
+            }
+            ("em", _) => match &mut code {
+                Some((_, emphasized)) => *emphasized = true,
+                None => out.push('*'),
+            },
+            ("a", false) => links.push((out.len(), get_attribute(tag, "href").unwrap_or_default())),
+            ("a", true) => {
+                if let Some((start, href)) = links.pop() {
+                    out.insert(start, '[');
+                    out.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out.trim().to_string() + "\n"
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        // whitespace is insignificant in html, newlines would break up paragraphs.
+        let text = text.replace('\n', " ");
+        if out.is_empty() || out.ends_with('\n') {
+            out.push_str(text.trim_start());
+        } else {
+            out.push_str(&text);
+        }
+    }
+}
+
+/// Ends the current line, unless it's empty.
+fn end_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// Ends the current block with an empty line.
+fn end_block(out: &mut String) {
+    end_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let (_, value) = tag.split_once(&format!("{name}=\""))?;
+    let (value, _) = value.split_once('"')?;
+    Some(decode_entities(value))
+}
+
+/// Decodes the html entities used on the website.
+#[must_use]
+pub fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{has_part_two, html_to_markdown, suggest_examples};
+
+    #[test]
+    fn renders_headings_and_paragraphs() {
+        let html = "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>\
+            <p>Something is wrong with global snow production.</p>\n<p>You try to ask why &amp; how.</p></article>";
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Trebuchet?! ---\n\nSomething is wrong with global snow production.\n\nYou try to ask why & how.\n"
+        );
+    }
+
+    #[test]
+    fn renders_code_blocks() {
+        let html = "<p>For example:</p>\n<pre><code>1abc2\npqr3stu8vwx\n&lt;em&gt;\n</code></pre>\n<p>Done.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "For example:\n\n```\n1abc2\npqr3stu8vwx\n<em>\n```\n\nDone.\n"
+        );
+    }
+
+    #[test]
+    fn renders_inline_formatting() {
+        let html = "<p>The values are <code>12</code>, <em>very</em> much <code><em>142</em></code>, see <a href=\"/2023/day/1/input\" target=\"_blank\">your input</a>.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "The values are `12`, *very* much *`142`*, see [your input](/2023/day/1/input).\n"
+        );
+    }
+
+    #[test]
+    fn renders_lists() {
+        let html = "<ul>\n<li><code>a</code> is one.</li>\n<li>b is two.</li>\n</ul>\n<p>Next.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "- `a` is one.\n- b is two.\n\nNext.\n"
+        );
+    }
+
+    #[test]
+    fn detects_part_two() {
+        let html = "<article><h2>--- Day 1 ---</h2></article><article><h2 id=\"part2\">--- Part Two ---</h2><p>More.</p></article>";
+        let markdown = html_to_markdown(html);
+        assert_eq!(
+            markdown,
+            "## --- Day 1 ---\n\n## --- Part Two ---\n\nMore.\n"
+        );
+        assert!(has_part_two(&markdown));
+        assert!(!has_part_two("## --- Day 1 ---"));
+    }
+
+    #[test]
+    fn suggests_examples() {
+        let markdown = "## --- Day 1: Trebuchet?! ---\n\n\
+            The values are on lines like `1abc2`:\n\n\
+            ```\nabc\n```\n\n\
+            For example:\n\n\
+            ```\n1abc2\npqr3stu8vwx\n```\n\n\
+            The values are `12` and `38`. Adding these together produces *`50`*.\n\n\
+            ## --- Part Two ---\n\n\
+            Using the same example, the sum is *`30`*.\n";
+
+        let suggestions = suggest_examples(markdown);
+        assert_eq!(suggestions.len(), 2);
+
+        assert_eq!(suggestions[0].part, 1);
+        assert_eq!(suggestions[0].examples, ["1abc2\npqr3stu8vwx\n"]);
+        assert_eq!(suggestions[0].answers, ["50"]);
+        assert!(!suggestions[0].is_ambiguous);
+
+        assert!(suggestions[1].examples.is_empty());
+        assert_eq!(suggestions[1].answers, ["30"]);
+    }
+
+    #[test]
+    fn flags_ambiguous_examples() {
+        let markdown = "Here is a loop:\n\n```\n.S-7.\n.L-J.\n```\n\n\
+            Here is the same loop with more pipes:\n\n```\n-L|F7\n7S-7|\nL|7||\n```\n\n\
+            The farthest pipe is *`4`* steps away, *`8`* in the second one.\n";
+
+        let suggestion = &suggest_examples(markdown)[0];
+        assert!(suggestion.is_ambiguous);
+        // the larger block comes first, its answer is the last one.
+        assert_eq!(suggestion.examples[0], "-L|F7\n7S-7|\nL|7||\n");
+        assert_eq!(suggestion.answers, ["8", "4"]);
+    }
+}
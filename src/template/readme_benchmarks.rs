@@ -1,8 +1,8 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io, time::Duration};
 
-use crate::Day;
+use crate::{Day, Puzzle, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,19 +21,36 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+/// Median execution time of a solution part and its standard deviation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartTiming {
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Display for PartTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.stddev.is_zero() {
+            write!(f, "{:.1?}", self.median)
+        } else {
+            write!(f, "{:.1?} ± {:.1?}", self.median, self.stddev)
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -58,7 +75,7 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn construct_table(prefix: &str, year: Year, timings: Vec<Timings>, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
     let mut lines: Vec<String> = vec![
@@ -70,13 +87,13 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(Puzzle::new(year, timing.day));
         lines.push(format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_1.map_or_else(|| "-".into(), |x| x.to_string()),
+            timing.part_2.map_or_else(|| "-".into(), |x| x.to_string())
         ));
     }
 
@@ -87,44 +104,58 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use std::time::Duration;
+
+    use super::{update_content, PartTiming, Timings, MARKER};
+    use crate::{day, year};
+
+    fn ms(median: u64, stddev: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            median: Duration::from_millis(median),
+            stddev: Duration::from_millis(stddev),
+        })
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                part_1: ms(10, 0),
+                part_2: ms(20, 0),
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                part_1: ms(30, 1),
+                part_2: ms(40, 2),
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                part_1: ms(40, 0),
+                part_2: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -134,28 +165,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +194,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -172,9 +203,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023_01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `30.0ms ± 1.0ms` | `40.0ms ± 2.0ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `40.0ms` | `-` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -0,0 +1,202 @@
+/// Machine-readable records of solution runs, emitted as one JSON object per line with `--format json`.
+use std::{fmt::Display, str::FromStr};
+
+use crate::template::runner::Stats;
+use crate::Puzzle;
+
+/// Output format of the `solve` and `all` commands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = FormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(FormatFromStrError),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Human => f.write_str("human"),
+            Format::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Format`].
+#[derive(Debug)]
+pub struct FormatFromStrError;
+
+impl std::error::Error for FormatFromStrError {}
+
+impl Display for FormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a format of either `human` or `json`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How a solution part finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    /// The part returned `None`.
+    NotImplemented,
+    /// The part returned an error.
+    Failed,
+    Panicked,
+    /// The part did not finish within its timeout.
+    TimedOut,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => f.write_str("solved"),
+            Status::NotImplemented => f.write_str("not_implemented"),
+            Status::Failed => f.write_str("failed"),
+            Status::Panicked => f.write_str("panicked"),
+            Status::TimedOut => f.write_str("timed_out"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome of running one part of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRecord {
+    pub puzzle: Puzzle,
+    /// The name of the input the part ran against, `None` for the account's input in `all`.
+    pub input: Option<String>,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// The error, panic or timeout message of a part that did not finish.
+    pub error: Option<String>,
+    /// Whether the answer matches the recorded answer, `None` if no answer was recorded.
+    pub correct: Option<bool>,
+    pub stats: Stats,
+}
+
+impl PartRecord {
+    /// Whether the part produced an answer.
+    #[must_use]
+    pub fn success(&self) -> bool {
+        self.status == Status::Solved
+    }
+
+    /// Serializes the record as a single-line JSON object.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let answer = self
+            .answer
+            .as_ref()
+            .map_or_else(|| "null".into(), |x| escape(x));
+        let error = self
+            .error
+            .as_ref()
+            .map_or_else(|| "null".into(), |x| escape(x));
+        let input = self
+            .input
+            .as_ref()
+            .map_or_else(|| "null".into(), |x| escape(x));
+
+        format!(
+            "{{\"year\":{},\"day\":{},\"input\":{},\"part\":{},\"answer\":{},\"success\":{},\"status\":\"{}\",\"error\":{},\"correct\":{},\"samples\":{},\"outliers\":{},\"duration_nanos\":{},\"mean_nanos\":{},\"stddev_nanos\":{},\"min_nanos\":{},\"p95_nanos\":{},\"max_nanos\":{}}}",
+            self.puzzle.year.into_inner(),
+            self.puzzle.day.into_inner(),
+            input,
+            self.part,
+            answer,
+            self.success(),
+            self.status,
+            error,
+            self.correct.map_or_else(|| "null".into(), |x| x.to_string()),
+            self.stats.samples,
+            self.stats.outliers,
+            self.stats.median.as_nanos(),
+            self.stats.mean.as_nanos(),
+            self.stats.stddev.as_nanos(),
+            self.stats.min.as_nanos(),
+            self.stats.p95.as_nanos(),
+            self.stats.max.as_nanos(),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Quotes a string as a JSON string.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A record of a part of 2023 day 5 for tests, solved if it has an answer and timed by `nanos`.
+#[cfg(all(test, feature = "test_lib"))]
+pub(crate) fn get_mock_record(part: u8, answer: Option<&str>, nanos: &[u64]) -> PartRecord {
+    let samples: Vec<std::time::Duration> = nanos
+        .iter()
+        .map(|x| std::time::Duration::from_nanos(*x))
+        .collect();
+    PartRecord {
+        puzzle: Puzzle::new(crate::year!(2023), crate::day!(5)),
+        input: None,
+        part,
+        answer: answer.map(Into::into),
+        status: if answer.is_some() {
+            Status::Solved
+        } else {
+            Status::NotImplemented
+        },
+        error: None,
+        correct: None,
+        stats: Stats::from_samples(&samples),
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{get_mock_record, PartRecord};
+
+    const NANOS: &[u64] = &[100, 120, 110];
+
+    #[test]
+    fn serializes_record() {
+        assert_eq!(
+            PartRecord {
+                correct: Some(true),
+                ..get_mock_record(2, Some("46"), NANOS)
+            }
+            .to_json(),
+            "{\"year\":2023,\"day\":5,\"input\":null,\"part\":2,\"answer\":\"46\",\"success\":true,\"status\":\"solved\",\"error\":null,\"correct\":true,\"samples\":3,\"outliers\":0,\"duration_nanos\":110,\"mean_nanos\":110,\"stddev_nanos\":8,\"min_nanos\":100,\"p95_nanos\":120,\"max_nanos\":120}"
+        );
+    }
+}
@@ -1,65 +1,542 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::answers::{self, Answers, Verdict};
+use crate::template::record::{Format, PartRecord, Status};
+use crate::template::submissions::{self, Guess, GuessLog, Outcome};
+use crate::template::{aoc_client, get_timestamp, ANSI_ITALIC, ANSI_RESET};
+use crate::{trace, viz, Puzzle};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Once};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
+
+use colored::Colorize;
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// A solution that is linked into the main binary, registered by the `solution!` macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: Puzzle,
+    pub solve: fn(&str, &mut Runner),
+}
+
+/// The return type of a solution part: `Option<T>`, or `Result<T, E>` for parts that can fail.
+pub trait PartResult {
+    type Answer: Display;
+
+    /// `Ok(None)` for parts that are not implemented yet, the rendered error for parts that failed.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| e.to_string())
+    }
+}
+
+/// What a solution part produced, see [`Status`].
+enum PartOutput<T> {
+    Solved(T),
+    NotImplemented,
+    Failed(String),
+    Panicked(String),
+    TimedOut(Duration),
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+impl<T> PartOutput<T> {
+    fn status(&self) -> Status {
+        match self {
+            PartOutput::Solved(_) => Status::Solved,
+            PartOutput::NotImplemented => Status::NotImplemented,
+            PartOutput::Failed(_) => Status::Failed,
+            PartOutput::Panicked(_) => Status::Panicked,
+            PartOutput::TimedOut(_) => Status::TimedOut,
+        }
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    fn answer(&self) -> Option<&T> {
+        match self {
+            PartOutput::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    fn error(&self) -> Option<String> {
+        match self {
+            PartOutput::Failed(e) | PartOutput::Panicked(e) => Some(e.clone()),
+            PartOutput::TimedOut(timeout) => Some(format!("timed out after {timeout:?}")),
+            _ => None,
+        }
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+/// Stack size of the threads that run parts with a timeout, the default of spawned threads is too small for deep recursions.
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
 
-    hook(&result);
+/// Runs a solution part on a worker thread and waits at most `timeout` for it to finish.
+/// Threads can't be cancelled, a part that times out keeps running in the background until the process exits.
+fn run_watched<R, F>(func: F, input: &str, timeout: Duration) -> (PartOutput<R::Answer>, Duration)
+where
+    R: PartResult,
+    R::Answer: Send + 'static,
+    F: Fn(&str) -> R + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+
+    let worker = thread::Builder::new()
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            let timer = Instant::now();
+            let output = run_caught(&func, input.as_str());
+            // the receiver is gone if the part timed out.
+            let _ = sender.send((output, timer.elapsed()));
+        });
+
+    if let Err(e) = worker {
+        return (
+            PartOutput::Failed(format!("could not spawn a thread: {e}")),
+            Duration::ZERO,
+        );
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(_) => (PartOutput::TimedOut(timeout), timeout),
+    }
+}
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+/// Whether a part timed out. Its thread keeps running until the process exits, so later parts are
+/// neither benched (it would skew their timings) nor traced or visualized (it would write into their traces and frames).
+static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Whether this thread is running a part, panics are only reported by [`run_caught`] meanwhile.
+    static IN_PART: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last panic on this thread while a part was running.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the default panic hook, once, with one that stays silent on threads that are running
+/// a part and remembers where the panic happened instead.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !IN_PART.with(Cell::get) {
+                default_hook(info);
+            } else if let Some(location) = info.location() {
+                PANIC_LOCATION.with(|l| *l.borrow_mut() = Some(location.to_string()));
+            }
+        }));
+    });
+}
+
+/// Runs a solution part, catching panics so that the remaining parts still run.
+/// The location of a panic is added to its message.
+fn run_caught<I, R: PartResult>(func: &impl Fn(I) -> R, input: I) -> PartOutput<R::Answer> {
+    install_panic_hook();
+    PANIC_LOCATION.with(|l| l.borrow_mut().take());
+
+    IN_PART.with(|p| p.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input).into_answer()));
+    IN_PART.with(|p| p.set(false));
+
+    match result {
+        Ok(Ok(Some(answer))) => PartOutput::Solved(answer),
+        Ok(Ok(None)) => PartOutput::NotImplemented,
+        Ok(Err(e)) => PartOutput::Failed(e),
+        Err(payload) => {
+            let message = get_panic_message(payload.as_ref());
+            match PANIC_LOCATION.with(|l| l.borrow_mut().take()) {
+                Some(location) => PartOutput::Panicked(format!("{message}\n  at {location}")),
+                None => PartOutput::Panicked(message),
+            }
+        }
+    }
+}
+
+fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
     } else {
-        (base_time, 1)
-    };
+        "unknown panic payload".into()
+    }
+}
+
+/// Settings that control how solution parts are run and reported.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Options {
+    /// Bench every part instead of running it once.
+    pub time: bool,
+    pub format: Format,
+    /// The part whose answer should be submitted.
+    pub submit: Option<u8>,
+    /// Submit even if the submission log suggests otherwise.
+    pub force: bool,
+    /// How long a part may run before it's reported as timed out, `None` waits forever.
+    /// Solutions can override it per day, see [`Runner::override_timeout`].
+    pub timeout: Option<Duration>,
+    /// Record the frames of every part and show or export them, see [`viz`].
+    pub visualize: Option<viz::Output>,
+    /// The parts whose traces are written, see [`trace`].
+    pub trace: Option<trace::Parts>,
+    /// Write traces to the log of the puzzle instead of stderr.
+    pub trace_log: bool,
+    /// The input is not the account's input, its answers are neither verified nor submitted.
+    pub custom_input: bool,
+}
+
+impl Options {
+    /// Reads the options from the command-line arguments of a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let value_of = |flag: &str| args.iter().position(|x| x == flag).map(|i| args.get(i + 1));
+
+        let submit =
+            value_of("--submit").map(|value| match value.and_then(|x| x.parse::<u8>().ok()) {
+                Some(part) => part,
+                None => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                    process::exit(1);
+                }
+            });
+
+        let trace_log = args.iter().any(|x| x == "--trace-log");
+        let trace = flag_value(&args, "--trace")
+            .map(
+                |value| match value.map_or(Ok(trace::Parts::Both), str::parse) {
+                    Ok(parts) => parts,
+                    Err(e) => {
+                        eprintln!("Invalid --trace: {e}. Format: cargo solve 1 --trace=part2");
+                        process::exit(1);
+                    }
+                },
+            )
+            // `--trace-log` alone traces both parts.
+            .or(trace_log.then_some(trace::Parts::Both));
+
+        Self {
+            time: args.iter().any(|x| x == "--time"),
+            format: value_of("--format")
+                .flatten()
+                .and_then(|x| x.parse().ok())
+                .unwrap_or_default(),
+            submit,
+            force: args.iter().any(|x| x == "--force"),
+            timeout: value_of("--timeout").map(|value| {
+                match value
+                    .ok_or_else(|| "missing value".into())
+                    .and_then(|x| parse_timeout(x))
+                {
+                    Ok(timeout) => timeout,
+                    Err(e) => {
+                        eprintln!("Invalid --timeout: {e}. Format: cargo solve 1 --timeout 30");
+                        process::exit(1);
+                    }
+                }
+            }),
+            visualize: flag_value(&args, "--visualize").map(|value| {
+                match value.map_or(Ok(viz::Output::default()), str::parse) {
+                    Ok(output) => output,
+                    Err(e) => {
+                        eprintln!(
+                            "Invalid --visualize: {e}. Format: cargo solve 1 --visualize=gif"
+                        );
+                        process::exit(1);
+                    }
+                }
+            }),
+            trace,
+            trace_log,
+            // set by `inputs::run`, which reads the input.
+            custom_input: false,
+        }
+    }
+}
+
+/// Finds a flag that takes an optional value, `--flag` or `--flag=value`.
+/// `None` if the flag is absent, `Some(None)` if it has no value.
+#[must_use]
+pub fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<Option<&'a str>> {
+    args.iter().find_map(|arg| match arg.strip_prefix(flag)? {
+        "" => Some(None),
+        rest => rest.strip_prefix('=').map(Some),
+    })
+}
+
+/// Parses a timeout in (fractional) seconds.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s
+        .parse()
+        .map_err(|_| format!("`{s}` is not a number of seconds"))?;
+
+    match Duration::try_from_secs_f64(secs) {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!("`{s}` is not a positive number of seconds")),
+    }
+}
+
+/// Destination of a runner's output.
+enum Sink {
+    Stdout,
+    Buffer(String),
+}
+
+impl Sink {
+    fn print(&mut self, s: &str) {
+        match self {
+            Sink::Stdout => {
+                print!("{s}");
+                let _ = stdout().flush();
+            }
+            Sink::Buffer(buffer) => buffer.push_str(s),
+        }
+    }
+}
+
+/// Runs solution parts according to its [`Options`] and collects their [`PartRecord`]s.
+pub struct Runner {
+    options: Options,
+    /// The name of the input, added to the records.
+    input: Option<String>,
+    records: Vec<PartRecord>,
+    out: Sink,
+}
+
+impl Runner {
+    /// Creates a runner that prints its output to stdout.
+    #[must_use]
+    pub fn new(options: Options) -> Self {
+        Self {
+            options,
+            input: None,
+            records: vec![],
+            out: Sink::Stdout,
+        }
+    }
+
+    /// Creates a runner that buffers its output instead of printing it, see [`Runner::output`].
+    #[must_use]
+    pub fn buffered(options: Options) -> Self {
+        Self {
+            out: Sink::Buffer(String::new()),
+            ..Self::new(options)
+        }
+    }
+
+    /// Creates a runner configured by the command-line arguments of a solution binary.
+    #[must_use]
+    pub fn from_args() -> Self {
+        Self::new(Options::from_args())
+    }
+
+    /// Replaces the timeout of the following parts, used by solutions that need more (or less) time
+    /// than the `--timeout` of the command line.
+    pub fn override_timeout(&mut self, timeout: Duration) {
+        self.options.timeout = Some(timeout);
+    }
+
+    /// Names the input of the following parts in their records, used when a day runs against several inputs.
+    pub fn set_input(&mut self, name: &str) {
+        self.input = Some(name.into());
+    }
+
+    /// Runs one part of a solution, see [`PartResult`] for the supported return types.
+    /// The part is run once and then benched (approx. 1 second of execution time or 10 samples,
+    /// whatever take longer) if timing is enabled and it produced an answer.
+    pub fn run_part<R, F>(&mut self, func: F, input: &str, puzzle: Puzzle, part: u8)
+    where
+        R: PartResult,
+        R::Answer: Send + 'static,
+        F: Fn(&str) -> R + Copy + Send + 'static,
+    {
+        let part_str = format!("Part {part}");
+        let is_human = self.options.format == Format::Human;
+        let after_timeout = TIMED_OUT.load(Ordering::SeqCst);
+
+        if after_timeout {
+            warn_after_timeout(&self.options);
+        }
+
+        if self.options.visualize.is_some() && !after_timeout {
+            viz::start();
+        }
+
+        let trace = self
+            .options
+            .trace
+            .filter(|parts| parts.contains(part) && !after_timeout);
+        if let Some(parts) = trace {
+            if cfg!(debug_assertions) {
+                let log = self.options.trace_log;
+                if let Err(e) = trace::start(puzzle, part, log, part == parts.first()) {
+                    eprintln!("Failed to open the trace log of {puzzle}: {e}");
+                }
+            } else if part == parts.first() {
+                eprintln!("Traces are compiled out of release builds, run without --release.");
+            }
+        }
+
+        let (output, base_time) = match self.options.timeout {
+            Some(timeout) => run_watched(func, input, timeout),
+            None => {
+                let timer = Instant::now();
+                let output = run_caught(&func, input);
+                (output, timer.elapsed())
+            }
+        };
+
+        if matches!(output, PartOutput::TimedOut(_)) {
+            TIMED_OUT.store(true, Ordering::SeqCst);
+        }
+
+        if trace.is_some() {
+            trace::finish();
+        }
+
+        if let (Some(visualize), false) = (self.options.visualize, after_timeout) {
+            // benching runs the part again, which must not record more frames.
+            let frames = viz::finish();
+            if !frames.is_empty() {
+                match viz::show(&frames, visualize, puzzle, part) {
+                    Ok(Some(path)) => eprintln!("Wrote visualization of {part_str} to {path:?}."),
+                    Ok(None) => {}
+                    Err(e) => eprintln!("Failed to write visualization of {part_str}: {e}"),
+                }
+            }
+        }
+
+        // only answers are benched, failures are reported right away.
+        let is_benched = self.options.time && output.answer().is_some() && !after_timeout;
+
+        if is_human {
+            print_result(&mut self.out, &output, &part_str, "");
+
+            if is_benched {
+                self.out
+                    .print(&format!(" > {ANSI_ITALIC}benching{ANSI_RESET}"));
+            }
+        }
+
+        let stats = if is_benched {
+            bench(func, input, &base_time)
+        } else {
+            Stats::single(base_time)
+        };
+
+        let answer = output.answer().map(ToString::to_string);
+        let verdict = match Answers::load(puzzle) {
+            _ if self.options.custom_input => Verdict::Unknown,
+            Ok(answers) => answers.verify(part, answer.as_deref()),
+            Err(e) => {
+                eprintln!("Failed to read answers for {puzzle}: {e}");
+                Verdict::Unknown
+            }
+        };
+
+        let record = PartRecord {
+            puzzle,
+            input: self.input.clone(),
+            part,
+            answer,
+            status: output.status(),
+            error: output.error(),
+            correct: verdict.as_bool(),
+            stats,
+        };
+
+        match self.options.format {
+            Format::Human => {
+                let suffix = format!("{}{}", format_verdict(&verdict), format_duration(&stats));
+                print_result(&mut self.out, &output, &part_str, &suffix);
+            }
+            Format::Json => self.out.print(&format!("{}\n", record.to_json())),
+        }
+
+        self.records.push(record);
 
-    (result, run.0, run.1)
+        if let (PartOutput::Solved(result), Some(submit_part)) = (output, self.options.submit) {
+            if submit_part == part && self.options.custom_input {
+                eprintln!(
+                    "Not submitting {part_str}, its answer is not for the input of your account."
+                );
+            } else if submit_part == part {
+                submit_result(result, puzzle, part, self.options.force);
+            }
+        }
+    }
+
+    /// The output of a [`Runner::buffered`] runner, empty for runners that print to stdout.
+    #[must_use]
+    pub fn output(&self) -> &str {
+        match &self.out {
+            Sink::Stdout => "",
+            Sink::Buffer(buffer) => buffer,
+        }
+    }
+
+    /// The records of all parts that were run so far.
+    #[must_use]
+    pub fn into_records(self) -> Vec<PartRecord> {
+        self.records
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Tells once that benching, tracing and visualizing stopped because a part timed out.
+fn warn_after_timeout(options: &Options) {
+    static WARN: Once = Once::new();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    if options.time || options.trace.is_some() || options.visualize.is_some() {
+        WARN.call_once(|| {
+            eprintln!("A part timed out and keeps running, the following parts are not benched, traced or visualized.");
+        });
+    }
+}
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+pub fn run_part<R, F>(func: F, input: &str, puzzle: Puzzle, part: u8)
+where
+    R: PartResult,
+    R::Answer: Send + 'static,
+    F: Fn(&str) -> R + Copy + Send + 'static,
+{
+    Runner::from_args().run_part(func, input, puzzle, part);
+}
 
-    let mut timers: Vec<Duration> = vec![];
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Stats {
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+
+    // warm up caches and branch predictors before recording anything.
+    let warmup_iterations = cmp::max(bench_iterations / WARMUP_DIVISOR, 1);
+
+    for _ in 0..warmup_iterations {
+        func(input.clone());
+    }
+
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
@@ -69,99 +546,362 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Fraction (`1 / WARMUP_DIVISOR`) of bench iterations that are run and discarded before sampling.
+const WARMUP_DIVISOR: u128 = 10;
+
+/// Summary statistics of a solution part's execution time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of samples the statistics were computed from (after outlier rejection).
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+impl Stats {
+    /// Statistics for a single, un-benched execution.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            outliers: 0,
+            min: duration,
+            median: duration,
+            p95: duration,
+            max: duration,
+            mean: duration,
+            stddev: Duration::ZERO,
+        }
+    }
+
+    /// Computes statistics over `samples`. Samples outside of Tukey's fences
+    /// (1.5 × the interquartile range beyond the first and third quartile) are discarded.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics without samples"
+        );
+
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = (q3 - q1) * 3 / 2;
+        let lower = q1.saturating_sub(fence);
+        let upper = q3 + fence;
+
+        let kept: Vec<u128> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (lower..=upper).contains(x))
+            .collect();
+
+        let count = kept.len() as u128;
+        let mean = kept.iter().sum::<u128>() / count;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = kept
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Self {
+            samples: count,
+            outliers: sorted.len() as u128 - count,
+            min: nanos(kept[0]),
+            median: nanos(percentile(&kept, 50.0)),
+            p95: nanos(percentile(&kept, 95.0)),
+            max: nanos(kept[kept.len() - 1]),
+            mean: nanos(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn nanos(n: u128) -> Duration {
+    Duration::from_nanos(n as u64)
+}
+
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => format!(" {}", "✔".green()),
+        Verdict::Incorrect { expected } => {
+            format!(" {}", format!("✘ expected {expected}").red())
+        }
+        Verdict::Unknown => String::new(),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_duration(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        return format!(" ({:.1?})", stats.median);
+    }
+
+    let outliers = match stats.outliers {
+        0 => String::new(),
+        n => format!(", {n} outliers"),
+    };
+
+    format!(
+        " ({:.1?} ± {:.1?} @ {} samples) [min {:.1?}, p95 {:.1?}, max {:.1?}{outliers}]",
+        stats.median, stats.stddev, stats.samples, stats.min, stats.p95, stats.max
+    )
+}
+
+fn print_result<T: Display>(
+    out: &mut Sink,
+    output: &PartOutput<T>,
+    part: &str,
+    duration_str: &str,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
+    match output {
+        PartOutput::Solved(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out.print(&str);
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out.print(&format!("\r{str}\n{result}\n"));
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out.print(&str);
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out.print(&format!("\r{str}\n"));
                 }
             }
         }
-        None => {
+        PartOutput::NotImplemented => {
+            if is_intermediate_result {
+                out.print(&format!("{part}: -"));
+            } else {
+                out.print(&format!(
+                    "\r{part}: {ANSI_ITALIC}not implemented{ANSI_RESET}             \n"
+                ));
+            }
+        }
+        PartOutput::TimedOut(timeout) => {
+            if is_intermediate_result {
+                out.print(&format!("{part}: ✖"));
+            } else {
+                let label = format!("✖ timed out after {timeout:?}");
+                out.print(&format!("\r{part}: {}\n", label.red()));
+            }
+        }
+        PartOutput::Failed(message) | PartOutput::Panicked(message) => {
+            let label = match output {
+                PartOutput::Panicked(_) => "✖ panicked",
+                _ => "✖ error",
+            };
+
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out.print(&format!("{part}: ✖"));
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out.print(&format!(
+                    "\r{part}: {}{duration_str}\n{message}\n",
+                    label.red()
+                ));
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Try to submit one part of the solution via the configured [`aoc_client`].
+/// Unless `force` is set, answers that are known to be wrong, identical resubmissions and
+/// submissions during the server's cooldown are refused locally. Every submission is recorded
+/// in the submission log of the puzzle, accepted answers also in `data/answers`.
+fn submit_result<T: Display>(result: T, puzzle: Puzzle, part: u8, force: bool) {
+    let answer = result.to_string();
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+    let log = match GuessLog::load(puzzle) {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!("Failed to read submission log: {e}");
+            process::exit(1);
+        }
+    };
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Some(reason) = log.check(part, &answer, get_timestamp()) {
+        if !force {
+            eprintln!("Refusing to submit: {reason}. Append `--force` to submit anyway.");
+            return;
+        }
+        eprintln!("Submitting despite: {reason}.");
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let client = match aoc_client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting result via {}...", client.name());
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let text = match client.submit(puzzle, part, &answer) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            return;
+        }
     };
 
-    if part_submit != part {
-        return None;
+    println!("{}", text.trim_end());
+    let response = submissions::parse_response(&text);
+
+    let guess = Guess {
+        timestamp: get_timestamp(),
+        part,
+        outcome: response.outcome,
+        wait: response.wait,
+        answer: answer.clone(),
+    };
+
+    if let Err(e) = GuessLog::append(puzzle, &guess) {
+        eprintln!("Failed to record submission: {e}");
+    }
+
+    if response.outcome == Outcome::Correct {
+        let mut answers = Answers::load(puzzle).unwrap_or_default();
+        answers.set(part, &answer);
+        match answers.save(puzzle) {
+            Ok(()) => println!(
+                "Recorded answer in \"{}\".",
+                answers::get_path(puzzle).display()
+            ),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{flag_value, format_duration, parse_timeout, run_watched, PartOutput, Stats};
+    use std::time::Duration;
+
+    fn ns(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_order_statistics() {
+        let stats = Stats::from_samples(&ns(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&ns(&[10, 11, 10, 12, 11, 10, 9000]));
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn computes_stddev() {
+        let stats = Stats::from_samples(&ns(&[1, 5, 1, 5]));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn formats_single_run() {
+        let stats = Stats::single(Duration::from_nanos(166));
+        assert_eq!(format_duration(&stats), " (166.0ns)");
+    }
+
+    #[test]
+    fn formats_benched_run() {
+        let stats = Stats::from_samples(&ns(&[1, 5, 1, 5]));
+        assert_eq!(
+            format_duration(&stats),
+            " (1.0ns ± 2.0ns @ 4 samples) [min 1.0ns, p95 5.0ns, max 5.0ns]"
+        );
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        assert_eq!(parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn finds_flag_values() {
+        let args: Vec<String> = ["01", "--visualize=gif", "--time"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(flag_value(&args, "--visualize"), Some(Some("gif")));
+        assert_eq!(flag_value(&args, "--time"), Some(None));
+        assert_eq!(flag_value(&args, "--timeout"), None);
+        assert_eq!(flag_value(&args, "--tim"), None);
+    }
+
+    #[test]
+    fn watches_parts() {
+        let (output, _) = run_watched(
+            |x: &str| x.parse::<u32>().ok(),
+            "42",
+            Duration::from_secs(5),
+        );
+        assert!(matches!(output, PartOutput::Solved(42)));
+
+        let (output, _) = run_watched(
+            |_: &str| -> Option<u32> { panic!("boom") },
+            "",
+            Duration::from_secs(5),
+        );
+        assert!(matches!(output, PartOutput::Panicked(message)
+            if message.starts_with("boom") && message.contains("at src/template/runner.rs")));
+
+        let timeout = Duration::from_millis(50);
+        let (output, duration) = run_watched(
+            |_: &str| -> Option<u32> {
+                loop {
+                    std::thread::sleep(Duration::from_secs(1))
+                }
+            },
+            "",
+            timeout,
+        );
+        assert!(matches!(output, PartOutput::TimedOut(t) if t == timeout));
+        assert_eq!(duration, timeout);
+    }
 }
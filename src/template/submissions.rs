@@ -0,0 +1,436 @@
+/// Module that interprets the server's response to a submission and keeps a log of every guess
+/// in `data/YYYY/submissions/DD.txt`, so known-wrong answers, identical resubmissions and submissions
+/// during the server's cooldown are refused before they hit the server.
+///
+/// Each line of a log is a tab-separated record of `timestamp, part, outcome, wait, answer`,
+/// where `wait` is the cooldown in seconds the server asked for (empty if none).
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::template::get_data_path;
+use crate::Puzzle;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint whether the answer is too high or too low.
+    Incorrect,
+    /// The answer was not checked because the last submission was too recent.
+    TooRecent,
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    #[must_use]
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooRecent => "too_recent",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "too_recent" => Ok(Outcome::TooRecent),
+            "wrong_level" => Ok(Outcome::WrongLevel),
+            "unknown" => Ok(Outcome::Unknown),
+            s => Err(Error::Parser(format!("Unknown submission outcome: {s}"))),
+        }
+    }
+}
+
+/// The server's response to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long the server asks to wait before the next submission.
+    pub wait: Option<Duration>,
+}
+
+/// Interprets the response text that aoc-cli prints after submitting an answer.
+#[must_use]
+pub fn parse_response(s: &str) -> Response {
+    // aoc-cli wraps the response text, normalize whitespace before matching.
+    let text = s
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    let outcome = if text.contains("that's the right answer") {
+        Outcome::Correct
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("that's not the right answer") {
+        Outcome::Incorrect
+    } else if text.contains("you gave an answer too recently") {
+        Outcome::TooRecent
+    } else if text.contains("you don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    };
+
+    Response {
+        outcome,
+        wait: parse_wait(&text),
+    }
+}
+
+/// Extracts waits like "please wait one minute" or "you have 4m 48s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let spec = before.rsplit("you have ").next()?;
+        let mut seconds = 0;
+
+        for token in spec.split_whitespace() {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            seconds += match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+        }
+
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let (_, after) = text.split_once("please wait ")?;
+    let mut words = after.split_whitespace();
+    let amount = words.next()?;
+    let unit = words.next()?;
+
+    let amount = match amount {
+        "one" | "a" | "an" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+
+    match unit.trim_end_matches(|c: char| !c.is_alphabetic()) {
+        "second" | "seconds" => Some(Duration::from_secs(amount)),
+        "minute" | "minutes" => Some(Duration::from_secs(amount * 60)),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A previously submitted answer and the server's response to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    /// Unix timestamp of the submission in seconds.
+    pub timestamp: u64,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+    pub answer: String,
+}
+
+impl Guess {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.part,
+            self.outcome,
+            self.wait
+                .map(|w| w.as_secs().to_string())
+                .unwrap_or_default(),
+            self.answer
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, Error> {
+        let err = || Error::Parser(format!("Malformed submission log line: {line}"));
+        let mut fields = line.splitn(5, '\t');
+        let mut next = || fields.next().ok_or_else(err);
+
+        Ok(Self {
+            timestamp: next()?.parse().map_err(|_| err())?,
+            part: next()?.parse().map_err(|_| err())?,
+            outcome: next()?.parse()?,
+            wait: match next()? {
+                "" => None,
+                s => Some(Duration::from_secs(s.parse().map_err(|_| err())?)),
+            },
+            answer: next()?.to_string(),
+        })
+    }
+
+    /// Unix timestamp from which on the server accepts submissions again.
+    #[must_use]
+    pub fn cooldown_until(&self) -> u64 {
+        self.timestamp + self.wait.map_or(0, |w| w.as_secs())
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path("submissions", puzzle, "txt")
+}
+
+/// Every guess submitted for a puzzle.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    /// Reads the log of a puzzle. A missing file is treated as an empty log.
+    pub fn load(puzzle: Puzzle) -> Result<Self, Error> {
+        let path = get_path(puzzle);
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Appends a guess to the log of a puzzle, creating `data/YYYY/submissions` if needed.
+    pub fn append(puzzle: Puzzle, guess: &Guess) -> Result<(), Error> {
+        let path = get_path(puzzle);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", guess.to_line())?;
+        Ok(())
+    }
+
+    /// Returns the reason why `answer` should not be submitted for `part` at time `now`, if any.
+    #[must_use]
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Option<String> {
+        if let Some(until) = self.guesses.iter().map(Guess::cooldown_until).max() {
+            if until > now {
+                return Some(format!("the server asked to wait, {}s left", until - now));
+            }
+        }
+
+        let guesses = || self.guesses.iter().filter(move |g| g.part == part);
+
+        // answers that were not judged for this part by the server may be submitted again.
+        if let Some(guess) = guesses().find(|g| {
+            g.answer == answer
+                && !matches!(
+                    g.outcome,
+                    Outcome::TooRecent | Outcome::WrongLevel | Outcome::Unknown
+                )
+        }) {
+            return Some(format!(
+                "{answer} was already submitted ({})",
+                guess.outcome
+            ));
+        }
+
+        let value: i128 = answer.trim().parse().ok()?;
+        let bound = |outcome: Outcome| {
+            guesses()
+                .filter(move |g| g.outcome == outcome)
+                .filter_map(|g| g.answer.trim().parse::<i128>().ok())
+        };
+
+        if let Some(high) = bound(Outcome::TooHigh).min().filter(|high| value >= *high) {
+            return Some(format!(
+                "{answer} is not lower than {high}, which is too high"
+            ));
+        }
+
+        if let Some(low) = bound(Outcome::TooLow).max().filter(|low| value <= *low) {
+            return Some(format!(
+                "{answer} is not higher than {low}, which is too low"
+            ));
+        }
+
+        None
+    }
+}
+
+impl FromStr for GuessLog {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let guesses = s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(Guess::from_line)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { guesses })
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_response, Guess, GuessLog, Outcome, Response};
+
+    #[test]
+    fn parses_correct_answer() {
+        let res = parse_response(
+            "That's the right answer! You are one gold star closer to restoring snow operations.",
+        );
+        assert_eq!(
+            res,
+            Response {
+                outcome: Outcome::Correct,
+                wait: None
+            }
+        );
+    }
+
+    #[test]
+    fn parses_wrong_answers() {
+        let res = parse_response(
+            "That's not the right answer; your answer is too high. If you're stuck, make sure\nyou're using the full input data. Please wait one minute before trying again.",
+        );
+        assert_eq!(res.outcome, Outcome::TooHigh);
+        assert_eq!(res.wait, Some(Duration::from_secs(60)));
+
+        let res = parse_response("That's not the right answer; your answer is too low.");
+        assert_eq!(res.outcome, Outcome::TooLow);
+
+        let res = parse_response(
+            "That's not the right answer. Please wait 5 minutes before trying again.",
+        );
+        assert_eq!(res.outcome, Outcome::Incorrect);
+        assert_eq!(res.wait, Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn parses_rate_limit() {
+        let res = parse_response(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 48s left to wait.",
+        );
+        assert_eq!(res.outcome, Outcome::TooRecent);
+        assert_eq!(res.wait, Some(Duration::from_secs(288)));
+    }
+
+    #[test]
+    fn roundtrips_guesses() {
+        let s = "1000\t1\ttoo_high\t60\t500\n1100\t2\tcorrect\t\t42\n";
+        let log: GuessLog = s.parse().unwrap();
+
+        assert_eq!(log.guesses.len(), 2);
+        assert_eq!(log.guesses[0].wait, Some(Duration::from_secs(60)));
+        assert_eq!(log.guesses[1].wait, None);
+        assert_eq!(
+            log.guesses.iter().map(Guess::to_line).collect::<Vec<_>>(),
+            s.lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log: GuessLog = [
+            "1\t1\ttoo_high\t\t500",
+            "2\t1\ttoo_low\t\t100",
+            "3\t1\tincorrect\t\tabc",
+            "4\t1\ttoo_recent\t\t250",
+            "5\t2\ttoo_high\t\t10",
+        ]
+        .join("\n")
+        .parse()
+        .unwrap();
+
+        assert!(log.check(1, "abc", 10).is_some());
+        assert!(log.check(1, "500", 10).is_some());
+        assert!(log.check(1, "600", 10).is_some());
+        assert!(log.check(1, "100", 10).is_some());
+        assert!(log.check(1, "50", 10).is_some());
+        assert!(log.check(1, "300", 10).is_none());
+        assert!(log.check(1, "250", 10).is_none());
+        assert!(log.check(1, "xyz", 10).is_none());
+        assert!(log.check(2, "300", 10).is_some());
+        assert!(log.check(2, "5", 10).is_none());
+    }
+
+    #[test]
+    fn refuses_identical_resubmissions() {
+        let log: GuessLog = "1\t1\tcorrect\t\t42\n2\t2\twrong_level\t\t7"
+            .parse()
+            .unwrap();
+
+        assert!(log.check(1, "42", 10).is_some());
+        assert!(log.check(2, "7", 10).is_none());
+        assert!(log.check(2, "8", 10).is_none());
+    }
+
+    #[test]
+    fn honors_cooldown() {
+        let log: GuessLog = "1000\t1\ttoo_high\t60\t500\n1010\t1\ttoo_recent\t30\t400"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            log.check(1, "300", 1050),
+            Some("the server asked to wait, 10s left".into())
+        );
+        assert!(log.check(1, "300", 1060).is_none());
+    }
+}
@@ -0,0 +1,187 @@
+/// Debug traces of solutions, enabled with `cargo solve <day> --trace[=part1|part2]` instead of
+/// commented-out `dbg!` calls. Traces go to stderr, or to `data/YYYY/traces/DD.log` with `--trace-log`.
+///
+/// The [`trace!`](crate::trace!) and [`trace_grid!`](crate::trace_grid!) macros compile to nothing
+/// in release builds, so benchmarks are not affected:
+/// ```ignore
+/// trace!("cycle of {} spins after {}", cycle.period, cycle.offset);
+/// trace_grid!(grid, "after {n} spins");
+/// ```
+use std::fmt::{self, Display};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, stderr, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::template::get_data_path;
+use crate::Puzzle;
+
+/// Writes a line to the trace of the running part, prefixed with its location like `dbg!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        if $crate::trace::is_enabled() {
+            $crate::trace::write(file!(), line!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Writes a grid (or anything else that spans multiple lines) to the trace of the running part,
+/// below a caption that defaults to the traced expression.
+#[macro_export]
+macro_rules! trace_grid {
+    ($grid:expr) => {
+        $crate::trace!("{}:\n{}", stringify!($grid), $grid)
+    };
+    ($grid:expr, $($arg:tt)+) => {
+        $crate::trace!("{}:\n{}", format_args!($($arg)+), $grid)
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Whether the running part is traced, to skip expensive preparations of traces otherwise.
+/// Always `false` in release builds.
+#[must_use]
+pub fn is_enabled() -> bool {
+    cfg!(debug_assertions) && ENABLED.load(Ordering::Relaxed)
+}
+
+/// Used by [`trace!`](crate::trace!), use the macro instead.
+#[doc(hidden)]
+pub fn write(file: &str, line: u32, message: fmt::Arguments) {
+    let mut sink = SINK.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(sink) = sink.as_mut() {
+        let _ = writeln!(sink, "[{file}:{line}] {message}");
+    }
+}
+
+/// Starts tracing part `part` of `puzzle` to stderr, or to [`get_path`] if `log` is set.
+/// The log is truncated if `truncate` is set, and appended to otherwise.
+pub fn start(puzzle: Puzzle, part: u8, log: bool, truncate: bool) -> io::Result<()> {
+    let mut sink: Box<dyn Write + Send> = if log {
+        Box::new(open_log(puzzle, truncate)?)
+    } else {
+        Box::new(stderr())
+    };
+
+    writeln!(sink, "== {puzzle}, part {part} ==")?;
+    *SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+    ENABLED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Stops tracing and flushes the trace.
+pub fn finish() {
+    ENABLED.store(false, Ordering::Relaxed);
+    if let Some(mut sink) = SINK.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        let _ = sink.flush();
+    }
+}
+
+/// The log of the traces of a puzzle, `data/YYYY/traces/DD.log`.
+#[must_use]
+pub fn get_path(puzzle: Puzzle) -> PathBuf {
+    get_data_path("traces", puzzle, "log")
+}
+
+fn open_log(puzzle: Puzzle, truncate: bool) -> io::Result<File> {
+    let path = get_path(puzzle);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(truncate)
+        .append(!truncate)
+        .open(path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The parts that are traced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    Only(u8),
+}
+
+impl Parts {
+    #[must_use]
+    pub fn contains(self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::Only(only) => only == part,
+        }
+    }
+
+    /// The first traced part, which starts a new log.
+    #[must_use]
+    pub fn first(self) -> u8 {
+        match self {
+            Parts::Both => 1,
+            Parts::Only(only) => only,
+        }
+    }
+}
+
+impl FromStr for Parts {
+    type Err = PartsFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "both" => Ok(Self::Both),
+            "part1" | "1" => Ok(Self::Only(1)),
+            "part2" | "2" => Ok(Self::Only(2)),
+            _ => Err(PartsFromStrError),
+        }
+    }
+}
+
+impl Display for Parts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Parts::Both => f.write_str("both"),
+            Parts::Only(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing [`Parts`].
+#[derive(Debug)]
+pub struct PartsFromStrError;
+
+impl std::error::Error for PartsFromStrError {}
+
+impl Display for PartsFromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expecting either `part1`, `part2` or `both`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Parts;
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!("part2".parse::<Parts>().unwrap(), Parts::Only(2));
+        assert_eq!("1".parse::<Parts>().unwrap(), Parts::Only(1));
+        assert_eq!(Parts::Only(2).to_string(), "part2");
+        assert!("part3".parse::<Parts>().is_err());
+
+        assert!(Parts::Both.contains(2));
+        assert!(!Parts::Only(1).contains(2));
+        assert_eq!(Parts::Only(2).first(), 2);
+    }
+}
@@ -0,0 +1,272 @@
+/// Encodes frames as an animated GIF (GIF89a with LZW compression), cells are drawn as colored squares.
+/// Captions are not rendered, GIFs have no text.
+use super::{color_of, Frame, BACKGROUND, PALETTE};
+use crate::grid::Pos;
+
+/// The longer side of the image in pixels is about this long, unless cells would get too big.
+const TARGET_SIZE: usize = 480;
+const MAX_CELL_SIZE: usize = 8;
+
+/// Delay between frames in hundredths of a second, the last frame is shown longer before looping.
+const FRAME_DELAY: u16 = 4;
+const LAST_FRAME_DELAY: u16 = 200;
+
+/// Longer recordings skip frames to keep the file size reasonable.
+const MAX_ENCODED_FRAMES: usize = 500;
+
+/// Bits per palette index, the palette has `2^PALETTE_BITS` colors.
+const PALETTE_BITS: u8 = 4;
+
+pub fn encode(frames: &[Frame]) -> Vec<u8> {
+    let (columns, rows) = frames.first().map_or((1, 1), |f| {
+        (f.cells.width().max(1), f.cells.height().max(1))
+    });
+    let cell_size = (TARGET_SIZE / columns.max(rows)).clamp(1, MAX_CELL_SIZE);
+    let width = (columns * cell_size).min(u16::MAX as usize);
+    let height = (rows * cell_size).min(u16::MAX as usize);
+
+    let mut out = b"GIF89a".to_vec();
+
+    // logical screen descriptor with a global color table.
+    push_u16(&mut out, width as u16);
+    push_u16(&mut out, height as u16);
+    out.extend([0x80 | 0x70 | (PALETTE_BITS - 1), BACKGROUND, 0]);
+    out.extend(PALETTE.iter().flatten());
+
+    // loops forever.
+    out.extend([0x21, 0xFF, 0x0B]);
+    out.extend(b"NETSCAPE2.0");
+    out.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    let step = frames.len().div_ceil(MAX_ENCODED_FRAMES).max(1);
+    let last = frames.len().saturating_sub(1);
+
+    for (i, frame) in frames.iter().enumerate() {
+        if i % step != 0 && i != last {
+            continue;
+        }
+
+        let delay = if i == last {
+            LAST_FRAME_DELAY
+        } else {
+            FRAME_DELAY
+        };
+        out.extend([0x21, 0xF9, 0x04, 0x00]);
+        push_u16(&mut out, delay);
+        out.extend([0x00, 0x00]);
+
+        // image descriptor covering the whole screen, without a local color table.
+        out.push(0x2C);
+        push_u16(&mut out, 0);
+        push_u16(&mut out, 0);
+        push_u16(&mut out, width as u16);
+        push_u16(&mut out, height as u16);
+        out.push(0);
+
+        let pixels = render(frame, width, height, cell_size);
+        out.push(PALETTE_BITS);
+        for block in lzw_compress(&pixels, PALETTE_BITS).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend(block);
+        }
+        out.push(0);
+    }
+
+    out.push(0x3B);
+    out
+}
+
+fn push_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend(value.to_le_bytes());
+}
+
+/// The palette index of every pixel, row by row.
+fn render(frame: &Frame, width: usize, height: usize, cell_size: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            let pos = Pos::new((x / cell_size) as i64, (y / cell_size) as i64);
+            pixels.push(if frame.cells.contains(pos) {
+                color_of(frame, pos)
+            } else {
+                BACKGROUND
+            });
+        }
+    }
+
+    pixels
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Codes are at most 12 bits long, the table is cleared when it's full.
+const MAX_CODES: u16 = 4096;
+
+/// Writes codes of variable length, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    len: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.len;
+        self.len += size;
+        while self.len >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses palette indices of `min_code_size` bits with the variable-length LZW of GIF.
+fn lzw_compress(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let alphabet = 1_usize << min_code_size;
+    let clear = 1_u16 << min_code_size;
+    let end = clear + 1;
+
+    // the code of every known string, indexed by the code of its prefix and its last index.
+    let mut table = vec![0_u16; MAX_CODES as usize * alphabet];
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size + 1;
+
+    let mut writer = BitWriter {
+        bytes: vec![],
+        buffer: 0,
+        len: 0,
+    };
+    writer.write(clear, code_size);
+
+    let mut prefix: Option<u16> = None;
+    for &index in indices {
+        let Some(code) = prefix else {
+            prefix = Some(u16::from(index));
+            continue;
+        };
+
+        let entry = code as usize * alphabet + index as usize;
+        if table[entry] != 0 {
+            prefix = Some(table[entry]);
+            continue;
+        }
+
+        writer.write(code, code_size);
+
+        if next_code < MAX_CODES {
+            table[entry] = next_code;
+            next_code += 1;
+            // decoders add their entries one code later, so they switch to longer codes one code later too.
+            if next_code > 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+        } else {
+            writer.write(clear, code_size);
+            table.fill(0);
+            next_code = end + 1;
+            code_size = min_code_size + 1;
+        }
+
+        prefix = Some(u16::from(index));
+    }
+
+    if let Some(code) = prefix {
+        writer.write(code, code_size);
+    }
+    writer.write(end, code_size);
+    writer.finish()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{encode, lzw_compress};
+    use crate::grid::Pos;
+    use crate::viz::Frame;
+
+    /// A straightforward GIF decoder, to check that decoders read what the encoder wrote.
+    fn lzw_decompress(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1_usize << min_code_size;
+        let end = clear + 1;
+
+        let mut bits = bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1));
+        let mut read = |size: u8| -> Option<usize> {
+            (0..size).try_fold(0, |code, i| Some(code | (usize::from(bits.next()?) << i)))
+        };
+
+        let reset = || -> Vec<Vec<u8>> { (0..clear + 2).map(|i| vec![i as u8]).collect() };
+        let mut table = reset();
+        let mut code_size = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = vec![];
+
+        while let Some(code) = read(code_size) {
+            if code == clear {
+                table = reset();
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                break;
+            }
+
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("unknown code {code}"),
+            };
+            out.extend(&entry);
+
+            if let Some(previous) = previous {
+                if table.len() < 4096 {
+                    table.push([previous, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+            previous = Some(entry);
+        }
+
+        out
+    }
+
+    #[test]
+    fn compresses_losslessly() {
+        let repetitive: Vec<u8> = (0..10_000).map(|i| (i / 7 % 3) as u8).collect();
+        let compressed = lzw_compress(&repetitive, 4);
+        assert!(compressed.len() < repetitive.len() / 4);
+        assert_eq!(lzw_decompress(&compressed, 4), repetitive);
+
+        // enough different strings to fill the table and clear it a few times.
+        let noisy: Vec<u8> = (0..50_000_u64)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 7) as u8 % 16)
+            .collect();
+        assert_eq!(lzw_decompress(&lzw_compress(&noisy, 4), 4), noisy);
+    }
+
+    #[test]
+    fn encodes_animations() {
+        let frame = Frame::new("#.\n.O".parse().unwrap());
+        let gif = encode(&[frame.clone(), frame.highlight([Pos::new(0, 0)])]);
+
+        assert!(gif.starts_with(b"GIF89a"));
+        // 2x2 cells of 8 pixels.
+        assert_eq!(gif[6..10], [16, 0, 16, 0]);
+        assert_eq!(gif.windows(2).filter(|w| *w == [0x21, 0xF9]).count(), 2);
+        assert_eq!(gif.last(), Some(&0x3B));
+    }
+}
@@ -0,0 +1,266 @@
+/// Records frames of a solution while it runs, to replay them in the terminal or export them
+/// as SVG files or an animated GIF with `cargo solve <day> --visualize[=svg|gif]`.
+///
+/// Solutions push frames with [`record`], which does nothing unless a visualization was requested:
+/// ```ignore
+/// viz::record(|| Frame::from_grid(&grid).highlight(moved).caption("tilted north"));
+/// ```
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::{fs, io};
+
+use crate::grid::{Grid, Pos};
+use crate::template::get_data_dir;
+use crate::Puzzle;
+
+mod gif;
+mod svg;
+mod terminal;
+
+/// A snapshot of a grid, with cells that stand out and an optional caption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<char>,
+    pub highlights: HashSet<Pos>,
+    pub caption: Option<String>,
+}
+
+impl Frame {
+    #[must_use]
+    pub fn new(cells: Grid<char>) -> Self {
+        Self {
+            cells,
+            highlights: HashSet::new(),
+            caption: None,
+        }
+    }
+
+    /// A frame that shows the first character of every cell's `Display`.
+    #[must_use]
+    pub fn from_grid<T: Display>(grid: &Grid<T>) -> Self {
+        Self::new(grid.map(|cell| cell.to_string().chars().next().unwrap_or(' ')))
+    }
+
+    #[must_use]
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Pos>) -> Self {
+        self.highlights.extend(positions);
+        self
+    }
+
+    #[must_use]
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Frames beyond this limit are dropped so that runaway loops don't exhaust the memory.
+const MAX_FRAMES: usize = 10_000;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static FRAMES: Mutex<Vec<Frame>> = Mutex::new(vec![]);
+
+/// Whether frames are being recorded, to skip expensive preparations of frames otherwise.
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Records the frame built by `frame`. It's only called while a visualization is being recorded,
+/// so solutions run at full speed otherwise.
+pub fn record(frame: impl FnOnce() -> Frame) {
+    if !is_recording() {
+        return;
+    }
+
+    let mut frames = FRAMES.lock().unwrap_or_else(|e| e.into_inner());
+    if frames.len() < MAX_FRAMES {
+        frames.push(frame());
+    }
+}
+
+/// Starts recording frames, drops the frames of a previous recording.
+pub fn start() {
+    FRAMES.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording and returns the recorded frames.
+pub fn finish() -> Vec<Frame> {
+    RECORDING.store(false, Ordering::Relaxed);
+    std::mem::take(&mut *FRAMES.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Colors of the exported visualizations: the background, highlighted cells, and the colors
+/// that characters are assigned to.
+const PALETTE: [[u8; 3]; 16] = [
+    [0x0f, 0x0f, 0x23],
+    [0xff, 0xff, 0x66],
+    [0xcc, 0xcc, 0xcc],
+    [0x00, 0xcc, 0x00],
+    [0x66, 0xcc, 0xff],
+    [0xff, 0x99, 0x00],
+    [0x99, 0x66, 0xff],
+    [0xff, 0x66, 0xcc],
+    [0x33, 0xff, 0xcc],
+    [0xff, 0x33, 0x33],
+    [0x33, 0x66, 0xff],
+    [0xcc, 0x66, 0x33],
+    [0x99, 0x99, 0x99],
+    [0x00, 0x99, 0x00],
+    [0x66, 0x66, 0x66],
+    [0xff, 0xff, 0xff],
+];
+
+const BACKGROUND: u8 = 0;
+const HIGHLIGHT: u8 = 1;
+
+/// The palette index of a cell: empty-looking cells are background, other characters
+/// always get the same color.
+fn color_of(frame: &Frame, pos: Pos) -> u8 {
+    if frame.highlights.contains(&pos) {
+        return HIGHLIGHT;
+    }
+
+    match frame.cells[pos] {
+        '.' | ' ' => BACKGROUND,
+        c => 2 + (c as u32 % (PALETTE.len() as u32 - 2)) as u8,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Where a visualization goes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Output {
+    /// An animation in the terminal.
+    #[default]
+    Terminal,
+    /// One SVG file per frame.
+    Svg,
+    /// An animated GIF.
+    Gif,
+}
+
+impl FromStr for Output {
+    type Err = OutputFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "terminal" => Ok(Self::Terminal),
+            "svg" => Ok(Self::Svg),
+            "gif" => Ok(Self::Gif),
+            _ => Err(OutputFromStrError),
+        }
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Output::Terminal => f.write_str("terminal"),
+            Output::Svg => f.write_str("svg"),
+            Output::Gif => f.write_str("gif"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`Output`].
+#[derive(Debug)]
+pub struct OutputFromStrError;
+
+impl std::error::Error for OutputFromStrError {}
+
+impl Display for OutputFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a visualization of either `terminal`, `svg` or `gif`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Where the visualizations of a part are exported, `data/YYYY/visualizations/DD-N` plus the
+/// extension of the file, or a directory of SVG files.
+#[must_use]
+pub fn get_path(puzzle: Puzzle, part: u8) -> PathBuf {
+    get_data_dir(puzzle.year)
+        .join("visualizations")
+        .join(format!("{}-{part}", puzzle.day))
+}
+
+/// Plays the frames in the terminal or writes them to [`get_path`], returns the path of written files.
+pub fn show(
+    frames: &[Frame],
+    output: Output,
+    puzzle: Puzzle,
+    part: u8,
+) -> io::Result<Option<PathBuf>> {
+    match output {
+        Output::Terminal => {
+            terminal::play(frames);
+            Ok(None)
+        }
+        Output::Svg => {
+            let dir = get_path(puzzle, part);
+            // frames of a previous, longer run would mix with the new ones.
+            if dir.exists() {
+                fs::remove_dir_all(&dir)?;
+            }
+            fs::create_dir_all(&dir)?;
+
+            for (i, frame) in frames.iter().enumerate() {
+                fs::write(dir.join(format!("{:04}.svg", i + 1)), svg::render(frame))?;
+            }
+            Ok(Some(dir))
+        }
+        Output::Gif => {
+            let path = get_path(puzzle, part).with_extension("gif");
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&path, gif::encode(frames))?;
+            Ok(Some(path))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{finish, record, start, Frame, Output};
+    use crate::grid::{Grid, Pos};
+
+    #[test]
+    fn records_frames_on_demand() {
+        let grid: Grid<char> = "#.\n.#".parse().unwrap();
+
+        record(|| panic!("frames are only built while recording"));
+
+        start();
+        record(|| Frame::from_grid(&grid).highlight([Pos::new(1, 1)]));
+        record(|| Frame::new(grid.clone()).caption("second"));
+        let frames = finish();
+        record(|| panic!("frames are only built while recording"));
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].cells, grid);
+        assert!(frames[0].highlights.contains(&Pos::new(1, 1)));
+        assert_eq!(frames[1].caption.as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn parses_outputs() {
+        assert_eq!("gif".parse::<Output>().unwrap(), Output::Gif);
+        assert_eq!(Output::Svg.to_string(), "svg");
+        assert!("png".parse::<Output>().is_err());
+    }
+}
@@ -0,0 +1,96 @@
+/// Renders frames as standalone SVG images, cells are drawn as colored squares.
+use std::fmt::Write;
+
+use super::{color_of, Frame, BACKGROUND, PALETTE};
+use crate::grid::Pos;
+
+/// Size of a cell in pixels.
+const CELL_SIZE: usize = 8;
+
+const CAPTION_HEIGHT: usize = 20;
+
+pub fn render(frame: &Frame) -> String {
+    let width = frame.cells.width() * CELL_SIZE;
+    let grid_height = frame.cells.height() * CELL_SIZE;
+    let height = grid_height
+        + if frame.caption.is_some() {
+            CAPTION_HEIGHT
+        } else {
+            0
+        };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+    let _ = writeln!(
+        svg,
+        "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+        hex(BACKGROUND)
+    );
+
+    for pos in frame.cells.positions() {
+        let color = color_of(frame, pos);
+        if color == BACKGROUND {
+            continue;
+        }
+
+        let Pos { x, y } = pos;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{}\" width=\"{CELL_SIZE}\" height=\"{CELL_SIZE}\" fill=\"{}\"/>",
+            x as usize * CELL_SIZE,
+            y as usize * CELL_SIZE,
+            hex(color)
+        );
+    }
+
+    if let Some(caption) = &frame.caption {
+        let _ = writeln!(
+            svg,
+            "<text x=\"4\" y=\"{}\" font-family=\"monospace\" font-size=\"14\" fill=\"{}\">{}</text>",
+            grid_height + CAPTION_HEIGHT - 5,
+            hex(super::HIGHLIGHT),
+            escape(caption)
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn hex(color: u8) -> String {
+    let [r, g, b] = PALETTE[color as usize];
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::render;
+    use crate::grid::Pos;
+    use crate::viz::Frame;
+
+    #[test]
+    fn renders_cells() {
+        let frame = Frame::new("#.\n.O".parse().unwrap())
+            .highlight([Pos::new(1, 1)])
+            .caption("a < b");
+        let svg = render(&frame);
+
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"16\" height=\"36\"")
+        );
+        // the background and one rect for each of the two visible cells.
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert!(svg.contains("<rect x=\"8\" y=\"8\" width=\"8\" height=\"8\" fill=\"#ffff66\"/>"));
+        assert!(svg.contains(">a &lt; b</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,59 @@
+/// Replays frames as an animation in the terminal.
+use std::io::{stdout, Write};
+use std::thread;
+use std::time::Duration;
+
+use colored::Colorize;
+
+use super::Frame;
+use crate::grid::Pos;
+
+const FRAME_DELAY: Duration = Duration::from_millis(40);
+
+/// Longer recordings skip frames so that the animation lasts about 10 seconds.
+const MAX_SHOWN_FRAMES: usize = 250;
+
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J";
+const ANSI_CURSOR_HOME: &str = "\x1b[H";
+
+pub fn play(frames: &[Frame]) {
+    let step = frames.len().div_ceil(MAX_SHOWN_FRAMES).max(1);
+    let mut out = stdout().lock();
+
+    let _ = write!(out, "{ANSI_CLEAR_SCREEN}");
+
+    // the last frame is always shown, it's usually the most interesting one.
+    let last = frames.len().saturating_sub(1);
+    for (i, frame) in frames.iter().enumerate() {
+        if i % step != 0 && i != last {
+            continue;
+        }
+
+        let _ = write!(out, "{ANSI_CURSOR_HOME}{}", render(frame));
+        let _ = out.flush();
+        thread::sleep(FRAME_DELAY);
+    }
+}
+
+fn render(frame: &Frame) -> String {
+    let mut rendered = String::new();
+
+    for (y, row) in frame.cells.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if frame.highlights.contains(&Pos::new(x as i64, y as i64)) {
+                rendered.push_str(&cell.to_string().yellow().bold().to_string());
+            } else {
+                rendered.push(*cell);
+            }
+        }
+        rendered.push('\n');
+    }
+
+    // pads the caption so that a shorter one overwrites the previous caption.
+    let caption = frame.caption.as_deref().unwrap_or_default();
+    rendered.push_str(&format!(
+        "{caption:<width$}\n",
+        width = frame.cells.width().max(40)
+    ));
+    rendered
+}
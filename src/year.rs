@@ -0,0 +1,153 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Day;
+
+/// The first year of advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent of code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year of advent of code,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle of a given year and day. Every file of a puzzle is namespaced by this key:
+/// solutions live in `src/bin/YYYY_DD.rs`, data in `data/YYYY/<folder>/DD.txt`.
+///
+/// # Display
+/// This value displays as `YYYY day DD`.
+///
+/// ```
+/// # use advent_of_code::{day, year, Puzzle};
+/// let puzzle = Puzzle::new(year!(2023), day!(8));
+/// assert_eq!(puzzle.to_string(), "2023 day 08");
+/// assert_eq!(puzzle.bin_name(), "2023_08");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// The name of the solution binary of this puzzle.
+    pub fn bin_name(self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value of 2015 or later"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Puzzle, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_year() {
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn orders_puzzles() {
+        let a = Puzzle::new(year!(2022), day!(25));
+        let b = Puzzle::new(year!(2023), day!(1));
+        assert!(a < b);
+        assert_eq!(b.bin_name(), "2023_01");
+    }
+}